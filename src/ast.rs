@@ -11,3 +11,19 @@ pub mod while_node;
 pub mod for_node;
 pub mod variable_node;
 
+pub mod list_node;
+pub mod map_node;
+pub mod tuple_node;
+pub mod return_node;
+pub mod argument;
+pub mod lambda_node;
+pub mod struct_def_node;
+pub mod enum_def_node;
+pub mod trait_def_node;
+pub mod impl_node;
+pub mod cast_node;
+pub mod conditional_node;
+pub mod declaration_node;
+pub mod import_node;
+pub mod string_literal_node;
+pub mod interpolated_string_node;
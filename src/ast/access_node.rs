@@ -1,11 +1,12 @@
 use string_interner::DefaultSymbol;
 use crate::ast::access_node::Member::Field;
+use crate::ast::argument::Argument;
 use crate::ast::arena_ast::ASTNodeId;
 
 #[derive(Debug)]
 pub struct AccessNode {
-    receiver: ASTNodeId,
-    member: Member,
+    pub receiver: ASTNodeId,
+    pub member: Member,
 }
 
 impl AccessNode {
@@ -17,14 +18,14 @@ impl AccessNode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Member {
     Field {
         name: DefaultSymbol,
     },
     Method {
         name: DefaultSymbol,
-        args: Option<ASTNodeId>,
+        args: Vec<Argument>,
     },
 }
 
@@ -35,17 +36,10 @@ impl Member {
         }
     }
     
-    pub fn method_no_args(name: DefaultSymbol) -> Self {
-        Self::Method {
-            name,
-            args: None,
-        }
-    }
-
-    pub fn method_with_args(name: DefaultSymbol, args: ASTNodeId) -> Self {
+    pub fn method(name: DefaultSymbol, args: Vec<Argument>) -> Self {
         Self::Method {
             name,
-            args: Some(args),
+            args,
         }
     }
 }
@@ -1,17 +1,21 @@
+use std::collections::HashSet;
+use string_interner::DefaultSymbol;
 use crate::ast::ast_node::ASTNode;
-use std::iter::Map;
-use std::ops::Range;
 
 
 #[derive(Debug)]
 pub struct AST {
     node_arena: Vec<ASTNode>,
+    top_level_nodes: Vec<ASTNodeId>,
+    exports: HashSet<DefaultSymbol>,
 }
 
 impl AST {
     pub fn new() -> Self {
         Self {
-            node_arena: vec![]
+            node_arena: vec![],
+            top_level_nodes: vec![],
+            exports: HashSet::new(),
         }
     }
 
@@ -21,6 +25,10 @@ impl AST {
         ASTNodeId(id)
     }
 
+    pub fn add_top_level_node(&mut self, id: ASTNodeId) {
+        self.top_level_nodes.push(id);
+    }
+
     pub fn lookup(&self, id: ASTNodeId) -> &ASTNode {
         &self.node_arena[id.0]
     }
@@ -29,11 +37,22 @@ impl AST {
         &mut self.node_arena[id.0]
     }
     
-    pub fn ast_node_id_iter(&self) -> NodeIdIter {
-        (0..self.node_arena.len()).map(|id| ASTNodeId(id))
+    pub fn nodes_mut(&mut self) -> impl Iterator<Item = &mut ASTNode> {
+        self.node_arena.iter_mut()
+    }
+
+    pub fn top_level_nodes(&self) -> Vec<ASTNodeId> {
+        self.top_level_nodes.clone()
+    }
+
+    pub fn add_export(&mut self, name: DefaultSymbol) {
+        self.exports.insert(name);
+    }
+
+    pub fn exports(&self) -> &HashSet<DefaultSymbol> {
+        &self.exports
     }
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct ASTNodeId(pub usize);
-pub type NodeIdIter = Map<Range<usize>, fn(usize) -> ASTNodeId>;
@@ -0,0 +1,20 @@
+use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::ASTNodeId;
+use crate::source::source_span::SourceSpan;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Argument {
+    pub keyword: Option<DefaultSymbol>,
+    pub value: ASTNodeId,
+    pub span: SourceSpan,
+}
+
+impl Argument {
+    pub fn new(keyword: Option<DefaultSymbol>, value: ASTNodeId, span: SourceSpan) -> Self {
+        Self {
+            keyword,
+            value,
+            span,
+        }
+    }
+}
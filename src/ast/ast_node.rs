@@ -1,16 +1,30 @@
 use crate::ast::access_node::AccessNode;
 use crate::ast::ast_node::ASTNodeType::*;
 use crate::ast::binary_operator_node::BinaryOperatorNode;
+use crate::ast::cast_node::CastNode;
+use crate::ast::conditional_node::ConditionalNode;
+use crate::ast::declaration_node::DeclarationNode;
+use crate::ast::enum_def_node::EnumDefNode;
+use crate::ast::impl_node::ImplNode;
+use crate::ast::import_node::ImportNode;
+use crate::ast::interpolated_string_node::InterpolatedStringNode;
 use crate::ast::for_node::ForNode;
 use crate::ast::function_call_node::FunctionCallNode;
 use crate::ast::function_def_node::FunctionDefNode;
 use crate::ast::if_node::IfNode;
 use crate::ast::index_node::IndexNode;
+use crate::ast::lambda_node::LambdaNode;
+use crate::ast::list_node::ListNode;
+use crate::ast::map_node::MapNode;
+use crate::ast::return_node::ReturnNode;
+use crate::ast::string_literal_node::StringLiteralNode;
+use crate::ast::struct_def_node::StructDefNode;
+use crate::ast::trait_def_node::TraitDefNode;
+use crate::ast::tuple_node::TupleNode;
 use crate::ast::unary_operator_node::UnaryOperatorNode;
 use crate::ast::variable_node::VariableNode;
 use crate::ast::while_node::WhileNode;
 use crate::source::source_span::SourceSpan;
-use string_interner::DefaultSymbol;
 use crate::compiler_context::scope::ScopeId;
 use crate::compiler_context::type_arena::DataTypeId;
 
@@ -35,8 +49,15 @@ impl ASTNode {
 
 #[derive(Debug)]
 pub enum ASTNodeType {
-    IntLiteral(DefaultSymbol),
-    StringLiteral(DefaultSymbol),
+    IntLiteral(i64),
+    StringLiteral(StringLiteralNode),
+    InterpolatedString(InterpolatedStringNode),
+
+    List(ListNode),
+
+    Map(MapNode),
+
+    Tuple(TupleNode),
 
     Variable(VariableNode),
 
@@ -44,8 +65,24 @@ pub enum ASTNodeType {
 
     BinaryOperator(BinaryOperatorNode),
 
+    Cast(CastNode),
+
+    Conditional(ConditionalNode),
+
+    Declaration(DeclarationNode),
+
     FunctionDef(FunctionDefNode),
 
+    StructDef(StructDefNode),
+
+    EnumDef(EnumDefNode),
+
+    TraitDef(TraitDefNode),
+
+    Impl(ImplNode),
+
+    Import(ImportNode),
+
     FunctionCall(FunctionCallNode),
 
     Index(IndexNode),
@@ -57,6 +94,10 @@ pub enum ASTNodeType {
     While(WhileNode),
 
     For(ForNode),
+
+    Return(ReturnNode),
+
+    Lambda(LambdaNode),
 }
 
 pub trait SpannableASTNode {
@@ -81,14 +122,29 @@ macro_rules! impl_to_ast_node_type {
 }
 
 impl_to_ast_node_type! {
+    StringLiteralNode => StringLiteral,
+    InterpolatedStringNode => InterpolatedString,
     VariableNode => Variable,
+    ListNode => List,
+    MapNode => Map,
+    TupleNode => Tuple,
     UnaryOperatorNode => UnaryOperator,
     BinaryOperatorNode => BinaryOperator,
+    CastNode => Cast,
+    ConditionalNode => Conditional,
     IndexNode => Index,
     AccessNode => Access,
     FunctionCallNode => FunctionCall,
+    DeclarationNode => Declaration,
     FunctionDefNode => FunctionDef,
+    StructDefNode => StructDef,
+    EnumDefNode => EnumDef,
+    TraitDefNode => TraitDef,
+    ImplNode => Impl,
+    ImportNode => Import,
     IfNode => If,
     WhileNode => While,
-    ForNode => For
+    ForNode => For,
+    ReturnNode => Return,
+    LambdaNode => Lambda
 }
@@ -0,0 +1,17 @@
+use crate::ast::arena_ast::ASTNodeId;
+use crate::types::type_annotation::TypeAnnotation;
+
+#[derive(Debug)]
+pub struct CastNode {
+    pub operand: ASTNodeId,
+    pub target: TypeAnnotation,
+}
+
+impl CastNode {
+    pub fn new(operand: ASTNodeId, target: TypeAnnotation) -> Self {
+        Self {
+            operand,
+            target
+        }
+    }
+}
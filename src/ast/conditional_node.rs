@@ -0,0 +1,18 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct ConditionalNode {
+    pub condition: ASTNodeId,
+    pub then_value: ASTNodeId,
+    pub else_value: ASTNodeId,
+}
+
+impl ConditionalNode {
+    pub fn new(condition: ASTNodeId, then_value: ASTNodeId, else_value: ASTNodeId) -> Self {
+        Self {
+            condition,
+            then_value,
+            else_value
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeclarationKind {
+    Let,
+    Const,
+}
+
+#[derive(Debug)]
+pub struct DeclarationNode {
+    pub kind: DeclarationKind,
+    pub target: ASTNodeId,
+    pub value: ASTNodeId,
+}
+
+impl DeclarationNode {
+    pub fn new(kind: DeclarationKind, target: ASTNodeId, value: ASTNodeId) -> Self {
+        Self {
+            kind,
+            target,
+            value
+        }
+    }
+}
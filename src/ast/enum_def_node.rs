@@ -0,0 +1,35 @@
+use string_interner::DefaultSymbol;
+use crate::types::type_annotation::TypeAnnotation;
+use crate::types::type_param::TypeParam;
+
+#[derive(Debug)]
+pub struct EnumDefNode {
+    pub name: DefaultSymbol,
+    pub type_params: Vec<TypeParam>,
+    pub variants: Vec<VariantDef>,
+}
+
+impl EnumDefNode {
+    pub fn new(name: DefaultSymbol, type_params: Vec<TypeParam>, variants: Vec<VariantDef>) -> Self {
+        Self {
+            name,
+            type_params,
+            variants,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VariantDef {
+    pub name: DefaultSymbol,
+    pub payload: Vec<TypeAnnotation>,
+}
+
+impl VariantDef {
+    pub fn new(name: DefaultSymbol, payload: Vec<TypeAnnotation>) -> Self {
+        Self {
+            name,
+            payload,
+        }
+    }
+}
@@ -1,19 +1,22 @@
 use string_interner::DefaultSymbol;
 use crate::ast::arena_ast::ASTNodeId;
+use crate::compiler_context::scope::ScopeId;
 
 #[derive(Debug)]
 pub struct ForNode {
-    item_identifier: DefaultSymbol,
-    iterator: ASTNodeId,
-    body: Vec<ASTNodeId>,
+    pub item_identifiers: Vec<DefaultSymbol>,
+    pub iterator: ASTNodeId,
+    pub body: Vec<ASTNodeId>,
+    pub body_scope: ScopeId,
 }
 
 impl ForNode {
-    pub fn new(item_identifier: DefaultSymbol, iterator: ASTNodeId, body: Vec<ASTNodeId>) -> Self {
+    pub fn new(item_identifiers: Vec<DefaultSymbol>, iterator: ASTNodeId, body: Vec<ASTNodeId>, body_scope: ScopeId) -> Self {
         Self {
-            item_identifier,
+            item_identifiers,
             iterator,
-            body
+            body,
+            body_scope,
         }
     }
 }
@@ -1,13 +1,14 @@
+use crate::ast::argument::Argument;
 use crate::ast::arena_ast::ASTNodeId;
 
 #[derive(Debug)]
 pub struct FunctionCallNode {
-    function: ASTNodeId,
-    args: Option<ASTNodeId>,
+    pub function: ASTNodeId,
+    pub args: Vec<Argument>,
 }
 
 impl FunctionCallNode {
-    pub fn new(function: ASTNodeId, args: Option<ASTNodeId>) -> Self {
+    pub fn new(function: ASTNodeId, args: Vec<Argument>) -> Self {
         Self {
             function,
             args,
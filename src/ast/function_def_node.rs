@@ -1,42 +1,56 @@
 use string_interner::DefaultSymbol;
 use crate::ast::arena_ast::ASTNodeId;
+use crate::compiler_context::scope::ScopeId;
+use crate::operators::overloaded_operator::OverloadedOperator;
 use crate::types::type_annotation::TypeAnnotation;
+use crate::types::type_param::TypeParam;
 
 #[derive(Debug)]
 pub struct FunctionDefNode {
-    name: DefaultSymbol,
-    params: Vec<Parameter>,
-    body: Vec<ASTNodeId>,
-    return_type: Option<TypeAnnotation>,
+    pub name: DefaultSymbol,
+    pub type_params: Vec<TypeParam>,
+    pub params: Vec<Parameter>,
+    pub body: Vec<ASTNodeId>,
+    pub return_type: Option<TypeAnnotation>,
+    pub body_scope: ScopeId,
+    pub operator: Option<OverloadedOperator>,
 }
 
 impl FunctionDefNode {
     pub fn new(
         name: DefaultSymbol,
+        type_params: Vec<TypeParam>,
         params: Vec<Parameter>,
         body: Vec<ASTNodeId>,
         return_type: Option<TypeAnnotation>,
+        body_scope: ScopeId,
+        operator: Option<OverloadedOperator>,
     ) -> Self {
         Self {
             name,
+            type_params,
             params,
             body,
-            return_type
+            return_type,
+            body_scope,
+            operator,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Parameter {
-    name: DefaultSymbol,
-    type_annotation: TypeAnnotation,
+    pub name: DefaultSymbol,
+    pub type_annotation: TypeAnnotation,
+    pub default_value: Option<ASTNodeId>,
 }
 
 impl Parameter {
-    pub fn new(name: DefaultSymbol, type_annotation: TypeAnnotation) -> Self {
+    pub fn new(name: DefaultSymbol, type_annotation: TypeAnnotation, default_value: Option<ASTNodeId>) -> Self {
         Self { 
             name, 
-            type_annotation 
+            type_annotation,
+            default_value,
         }
     }
 }
@@ -2,8 +2,8 @@ use crate::ast::arena_ast::ASTNodeId;
 
 #[derive(Debug)]
 pub struct IfNode {
-    condition_blocks: Vec<ConditionBlock>,
-    else_body: Option<Vec<ASTNodeId>>,
+    pub condition_blocks: Vec<ConditionBlock>,
+    pub else_body: Option<Vec<ASTNodeId>>,
 }
 
 impl IfNode {
@@ -15,10 +15,10 @@ impl IfNode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ConditionBlock {
-    condition: ASTNodeId,
-    body: Vec<ASTNodeId>,
+    pub condition: ASTNodeId,
+    pub body: Vec<ASTNodeId>,
 }
 
 impl ConditionBlock {
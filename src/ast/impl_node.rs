@@ -0,0 +1,27 @@
+use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::ASTNodeId;
+use crate::types::type_param::TypeParam;
+
+#[derive(Debug)]
+pub struct ImplNode {
+    pub trait_name: DefaultSymbol,
+    pub type_name: DefaultSymbol,
+    pub type_params: Vec<TypeParam>,
+    pub methods: Vec<ASTNodeId>,
+}
+
+impl ImplNode {
+    pub fn new(
+        trait_name: DefaultSymbol,
+        type_name: DefaultSymbol,
+        type_params: Vec<TypeParam>,
+        methods: Vec<ASTNodeId>,
+    ) -> Self {
+        Self {
+            trait_name,
+            type_name,
+            type_params,
+            methods,
+        }
+    }
+}
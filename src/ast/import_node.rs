@@ -0,0 +1,35 @@
+use string_interner::DefaultSymbol;
+use crate::compiler_context::module::ModuleId;
+use crate::source::source_span::SourceSpan;
+
+#[derive(Debug, Clone)]
+pub struct ImportedName {
+    pub name: DefaultSymbol,
+    pub span: SourceSpan,
+}
+
+impl ImportedName {
+    pub fn new(name: DefaultSymbol, span: SourceSpan) -> Self {
+        Self {
+            name,
+            span,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportNode {
+    pub path: Vec<DefaultSymbol>,
+    pub names: Option<Vec<ImportedName>>,
+    pub module: Option<ModuleId>,
+}
+
+impl ImportNode {
+    pub fn new(path: Vec<DefaultSymbol>, names: Option<Vec<ImportedName>>) -> Self {
+        Self {
+            path,
+            names,
+            module: None,
+        }
+    }
+}
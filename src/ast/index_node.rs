@@ -1,16 +1,17 @@
+use crate::ast::argument::Argument;
 use crate::ast::arena_ast::ASTNodeId;
 
 #[derive(Debug)]
 pub struct IndexNode {
-    operand: ASTNodeId,
-    arg: ASTNodeId,
+    pub operand: ASTNodeId,
+    pub args: Vec<Argument>,
 }
 
 impl IndexNode {
-    pub fn new(operand: ASTNodeId, arg: ASTNodeId) -> Self {
+    pub fn new(operand: ASTNodeId, args: Vec<Argument>) -> Self {
         Self {
             operand,
-            arg
+            args,
         }
     }
 }
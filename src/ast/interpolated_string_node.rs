@@ -0,0 +1,14 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct InterpolatedStringNode {
+    pub parts: Vec<ASTNodeId>,
+}
+
+impl InterpolatedStringNode {
+    pub fn new(parts: Vec<ASTNodeId>) -> Self {
+        Self {
+            parts
+        }
+    }
+}
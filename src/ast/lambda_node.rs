@@ -0,0 +1,39 @@
+use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::ASTNodeId;
+use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::symbol::SymbolId;
+use crate::types::type_annotation::TypeAnnotation;
+
+#[derive(Debug, Clone)]
+pub struct LambdaParam {
+    pub name: DefaultSymbol,
+    pub type_annotation: Option<TypeAnnotation>,
+}
+
+impl LambdaParam {
+    pub fn new(name: DefaultSymbol, type_annotation: Option<TypeAnnotation>) -> Self {
+        Self {
+            name,
+            type_annotation,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LambdaNode {
+    pub params: Vec<LambdaParam>,
+    pub body: ASTNodeId,
+    pub body_scope: ScopeId,
+    pub captures: Vec<SymbolId>,
+}
+
+impl LambdaNode {
+    pub fn new(params: Vec<LambdaParam>, body: ASTNodeId, body_scope: ScopeId) -> Self {
+        Self {
+            params,
+            body,
+            body_scope,
+            captures: Vec::new(),
+        }
+    }
+}
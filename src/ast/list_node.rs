@@ -0,0 +1,14 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct ListNode {
+    pub elements: Vec<ASTNodeId>,
+}
+
+impl ListNode {
+    pub fn new(elements: Vec<ASTNodeId>) -> Self {
+        Self {
+            elements
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct MapNode {
+    pub entries: Vec<MapEntry>,
+}
+
+impl MapNode {
+    pub fn new(entries: Vec<MapEntry>) -> Self {
+        Self {
+            entries
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct MapEntry {
+    pub key: ASTNodeId,
+    pub value: ASTNodeId,
+}
+
+impl MapEntry {
+    pub fn new(key: ASTNodeId, value: ASTNodeId) -> Self {
+        Self {
+            key,
+            value
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct ReturnNode {
+    pub value: Option<ASTNodeId>,
+}
+
+impl ReturnNode {
+    pub fn new(value: Option<ASTNodeId>) -> Self {
+        Self {
+            value
+        }
+    }
+}
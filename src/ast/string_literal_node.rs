@@ -0,0 +1,19 @@
+use string_interner::DefaultSymbol;
+
+#[derive(Debug)]
+pub struct StringLiteralNode {
+    /// The source text, with its escape sequences undecoded. Nothing reads it yet, it is kept
+    /// for diagnostics and code generation.
+    #[allow(dead_code)]
+    pub raw: DefaultSymbol,
+    pub value: DefaultSymbol,
+}
+
+impl StringLiteralNode {
+    pub fn new(raw: DefaultSymbol, value: DefaultSymbol) -> Self {
+        Self {
+            raw,
+            value
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::ASTNodeId;
+use crate::types::type_annotation::TypeAnnotation;
+use crate::types::type_param::TypeParam;
+
+#[derive(Debug)]
+pub struct StructDefNode {
+    pub name: DefaultSymbol,
+    pub type_params: Vec<TypeParam>,
+    pub fields: Vec<FieldDef>,
+    pub methods: Vec<ASTNodeId>,
+}
+
+impl StructDefNode {
+    pub fn new(
+        name: DefaultSymbol,
+        type_params: Vec<TypeParam>,
+        fields: Vec<FieldDef>,
+        methods: Vec<ASTNodeId>,
+    ) -> Self {
+        Self {
+            name,
+            type_params,
+            fields,
+            methods,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldDef {
+    pub name: DefaultSymbol,
+    pub type_annotation: TypeAnnotation,
+}
+
+impl FieldDef {
+    pub fn new(name: DefaultSymbol, type_annotation: TypeAnnotation) -> Self {
+        Self {
+            name,
+            type_annotation,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct TraitDefNode {
+    pub name: DefaultSymbol,
+    pub methods: Vec<ASTNodeId>,
+}
+
+impl TraitDefNode {
+    pub fn new(name: DefaultSymbol, methods: Vec<ASTNodeId>) -> Self {
+        Self {
+            name,
+            methods,
+        }
+    }
+}
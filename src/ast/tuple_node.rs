@@ -0,0 +1,14 @@
+use crate::ast::arena_ast::ASTNodeId;
+
+#[derive(Debug)]
+pub struct TupleNode {
+    pub elements: Vec<ASTNodeId>,
+}
+
+impl TupleNode {
+    pub fn new(elements: Vec<ASTNodeId>) -> Self {
+        Self {
+            elements
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::types::type_annotation::TypeAnnotation;
 #[derive(Debug)]
 pub struct VariableNode {
     pub name: DefaultSymbol,
-    type_annotation: Option<TypeAnnotation>,
+    pub type_annotation: Option<TypeAnnotation>,
 }
 
 impl VariableNode {
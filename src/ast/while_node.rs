@@ -2,8 +2,8 @@ use crate::ast::arena_ast::ASTNodeId;
 
 #[derive(Debug)]
 pub struct WhileNode {
-    condition: ASTNodeId,
-    body: Vec<ASTNodeId>,
+    pub condition: ASTNodeId,
+    pub body: Vec<ASTNodeId>,
 }
 
 impl WhileNode {
@@ -2,15 +2,24 @@ pub mod symbol_table;
 pub mod type_arena;
 pub mod scope;
 pub mod symbol;
+pub mod module;
+pub mod module_table;
 
 use string_interner::{DefaultBackend, DefaultSymbol, StringInterner};
+use crate::compiler_context::module_table::ModuleTable;
 use crate::compiler_context::symbol_table::SymbolTable;
 use crate::compiler_context::type_arena::TypeArena;
+use crate::operators::binary_operators::BinaryOperator;
+use crate::operators::unary_operators::UnaryOperator;
+use crate::semantic::type_synthesis::operator_registry::OperatorRegistry;
 
 pub struct CompilerContext {
     string_interner: StringInterner<DefaultBackend>,
     pub type_arena: TypeArena,
     pub symbol_table: SymbolTable,
+    pub modules: ModuleTable,
+    pub unary_op_impl: OperatorRegistry<UnaryOperator>,
+    pub binary_op_impl: OperatorRegistry<BinaryOperator>,
 }
 
 impl CompilerContext {
@@ -19,6 +28,9 @@ impl CompilerContext {
             string_interner: StringInterner::default(),
             type_arena: TypeArena::new(),
             symbol_table: SymbolTable::new(),
+            modules: ModuleTable::new(),
+            unary_op_impl: OperatorRegistry::new(),
+            binary_op_impl: OperatorRegistry::new(),
         }
     }
 
@@ -26,7 +38,7 @@ impl CompilerContext {
         self.string_interner.get_or_intern(string)
     }
 
-    pub fn get_str(&mut self, symbol: DefaultSymbol) -> Option<&str> {
+    pub fn get_str(&self, symbol: DefaultSymbol) -> Option<&str> {
         self.string_interner.resolve(symbol)
     }
 }
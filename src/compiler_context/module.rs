@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use string_interner::DefaultSymbol;
+use crate::compiler_context::scope::ScopeId;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ModuleId(usize);
+
+impl ModuleId {
+    pub fn new(id: usize) -> Self {
+        Self(id)
+    }
+
+    pub fn as_usize(&self) -> usize {
+        self.0
+    }
+}
+
+pub struct Module {
+    pub name: DefaultSymbol,
+    pub path: PathBuf,
+    pub scope: ScopeId,
+    pub exports: HashSet<DefaultSymbol>,
+}
+
+impl Module {
+    pub fn new(name: DefaultSymbol, path: PathBuf, scope: ScopeId, exports: HashSet<DefaultSymbol>) -> Self {
+        Self {
+            name,
+            path,
+            scope,
+            exports,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::Path;
+use crate::compiler_context::module::{Module, ModuleId};
+
+pub struct ModuleTable {
+    modules: Vec<Module>,
+}
+
+impl ModuleTable {
+    pub fn new() -> Self {
+        Self {
+            modules: Vec::new(),
+        }
+    }
+
+    pub fn add_module(&mut self, module: Module) -> ModuleId {
+        let id = self.modules.len();
+        self.modules.push(module);
+        ModuleId::new(id)
+    }
+
+    pub fn module(&self, id: ModuleId) -> &Module {
+        &self.modules[id.as_usize()]
+    }
+
+    pub fn find(&self, path: &Path) -> Option<ModuleId> {
+        self.modules
+            .iter()
+            .position(|module| module.path == path)
+            .map(ModuleId::new)
+    }
+}
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 use string_interner::DefaultSymbol;
-use crate::compiler_context::symbol::Symbol;
+use crate::compiler_context::symbol::{NamedType, Symbol};
 
 pub struct Scope {
     symbols: HashMap<DefaultSymbol, Symbol>,
+    types: HashMap<DefaultSymbol, NamedType>,
     pub(crate) parent: Option<ScopeId>,
 }
 
@@ -12,13 +13,15 @@ impl Scope {
         Self {
             parent: None,
             symbols: HashMap::new(),
+            types: HashMap::new(),
         }
     }
 
     pub fn with_parent(parent: ScopeId) -> Self {
         Self {
             parent: Some(parent),
-            symbols: HashMap::new()
+            symbols: HashMap::new(),
+            types: HashMap::new(),
         }
     }
 
@@ -26,6 +29,14 @@ impl Scope {
         self.symbols.insert(symbol.name, symbol);
     }
 
+    pub fn add_type(&mut self, name: DefaultSymbol, named_type: NamedType) {
+        self.types.insert(name, named_type);
+    }
+
+    pub fn lookup_type(&self, name: DefaultSymbol) -> Option<NamedType> {
+        self.types.get(&name).copied()
+    }
+
     pub fn lookup(&self, name: DefaultSymbol) -> Option<&Symbol> {
         match self.symbols.get(&name) {
             Some(symbol) => Some(symbol),
@@ -34,7 +45,7 @@ impl Scope {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct ScopeId(usize);
 
 impl ScopeId {
@@ -1,9 +1,95 @@
 use string_interner::DefaultSymbol;
-use crate::compiler_context::type_arena::DataTypeId;
+use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::type_arena::{DataTypeId, TraitId, UserTypeId};
 use crate::source::source_span::SourceSpan;
+use crate::types::const_value::ConstValue;
+use crate::types::function_signature::FunctionSignature;
+use crate::types::type_param::TypeParam;
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mutability {
+    Mutable,
+    Immutable,
+    Const(ConstValue),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SymbolId {
+    pub scope: ScopeId,
+    pub name: DefaultSymbol,
+}
+
+impl SymbolId {
+    pub fn new(scope: ScopeId, name: DefaultSymbol) -> Self {
+        Self {
+            scope,
+            name,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NamedType {
+    UserType(UserTypeId),
+    Trait(TraitId),
+}
+
+#[derive(Clone)]
 pub struct Symbol {
     pub name: DefaultSymbol,
     pub data_type: DataTypeId,
+    pub signature: Option<FunctionSignature>,
+    pub type_params: Vec<TypeParam>,
+    pub mutability: Mutability,
     def_span: SourceSpan,
 }
+
+impl Symbol {
+    pub fn new(name: DefaultSymbol, data_type: DataTypeId, def_span: SourceSpan) -> Self {
+        Self {
+            name,
+            data_type,
+            signature: None,
+            type_params: Vec::new(),
+            mutability: Mutability::Mutable,
+            def_span,
+        }
+    }
+
+    pub fn generic(name: DefaultSymbol, data_type: DataTypeId, type_params: Vec<TypeParam>, def_span: SourceSpan) -> Self {
+        Self {
+            type_params,
+            mutability: Mutability::Immutable,
+            ..Self::new(name, data_type, def_span)
+        }
+    }
+
+    pub fn function(name: DefaultSymbol, data_type: DataTypeId, signature: FunctionSignature, def_span: SourceSpan) -> Self {
+        Self {
+            name,
+            data_type,
+            type_params: signature.type_params.clone(),
+            signature: Some(signature),
+            mutability: Mutability::Immutable,
+            def_span,
+        }
+    }
+
+    pub fn with_mutability(self, mutability: Mutability) -> Self {
+        Self {
+            mutability,
+            ..self
+        }
+    }
+
+    pub fn with_def_span(self, def_span: SourceSpan) -> Self {
+        Self {
+            def_span,
+            ..self
+        }
+    }
+
+    pub fn def_span(&self) -> SourceSpan {
+        self.def_span
+    }
+}
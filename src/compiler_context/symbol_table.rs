@@ -1,5 +1,5 @@
 use crate::compiler_context::scope::{Scope, ScopeId};
-use crate::compiler_context::symbol::Symbol;
+use crate::compiler_context::symbol::{NamedType, Symbol, SymbolId};
 use string_interner::DefaultSymbol;
 
 pub struct SymbolTable {
@@ -23,6 +23,72 @@ impl SymbolTable {
         ScopeId::new(id)
     }
 
+    pub fn add_module_scope(&mut self) -> ScopeId {
+        self.add_scope(Scope::with_parent(self.global_scope()))
+    }
+
+    pub fn is_top_level(&self, scope: ScopeId) -> bool {
+        scope == self.global_scope() || self.scopes[scope.as_usize()].parent == Some(self.global_scope())
+    }
+
+    pub fn add_symbol(&mut self, scope: ScopeId, symbol: Symbol) {
+        self.scopes[scope.as_usize()].add_symbol(symbol);
+    }
+
+    pub fn add_type(&mut self, scope: ScopeId, name: DefaultSymbol, named_type: NamedType) {
+        self.scopes[scope.as_usize()].add_type(name, named_type);
+    }
+
+    pub fn declares_type(&self, scope: ScopeId, name: DefaultSymbol) -> bool {
+        self.scopes[scope.as_usize()].lookup_type(name).is_some()
+    }
+
+    pub fn lookup_type(&self, name: DefaultSymbol, scope: ScopeId) -> Option<NamedType> {
+        let mut curr_scope = Some(scope);
+
+        while let Some(id) = curr_scope {
+            let scope = &self.scopes[id.as_usize()];
+
+            if let Some(named_type) = scope.lookup_type(name) {
+                return Some(named_type);
+            }
+
+            curr_scope = scope.parent;
+        }
+
+        None
+    }
+
+    pub fn resolve(&self, name: DefaultSymbol, scope: ScopeId) -> Option<SymbolId> {
+        let mut curr_scope = Some(scope);
+
+        while let Some(id) = curr_scope {
+            let scope = &self.scopes[id.as_usize()];
+
+            if scope.lookup(name).is_some() {
+                return Some(SymbolId::new(id, name));
+            }
+
+            curr_scope = scope.parent;
+        }
+
+        None
+    }
+
+    pub fn is_within(&self, scope: ScopeId, ancestor: ScopeId) -> bool {
+        let mut curr_scope = Some(scope);
+
+        while let Some(id) = curr_scope {
+            if id == ancestor {
+                return true;
+            }
+
+            curr_scope = self.scopes[id.as_usize()].parent;
+        }
+
+        false
+    }
+
     pub fn lookup(&self, name: DefaultSymbol, scope: ScopeId) -> Option<&Symbol> {
         
         let mut curr_scope = Some(scope);
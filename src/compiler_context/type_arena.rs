@@ -1,21 +1,62 @@
+use std::collections::HashMap;
+use string_interner::DefaultSymbol;
 use strum::IntoEnumIterator;
+use crate::compiler_context::module::ModuleId;
 use crate::types::data_type::{BuiltinType, DataType};
+use crate::source::source_span::SourceSpan;
+use crate::types::trait_def::TraitDef;
+use crate::types::user_type::UserTypeDef;
 
 
 pub struct TypeArena {
     data_types: Vec<DataType>,
+    interned_types: HashMap<DataType, DataTypeId>,
+    user_types: Vec<UserTypeDef>,
+    traits: Vec<TraitDef>,
+    type_vars: Vec<TypeVarState>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct TypeVarState {
+    binding: Option<DataTypeId>,
+    bound_at: Option<SourceSpan>,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct DataTypeId(usize);
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct UserTypeId(usize);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TraitId(usize);
+
 impl TypeArena {
     pub fn new() -> Self {
-        Self {
-            data_types: BuiltinType::iter()
-                .map(|t| DataType::Builtin(t))
-                .collect(),
+        let mut type_arena = Self {
+            data_types: Vec::new(),
+            interned_types: HashMap::new(),
+            user_types: Vec::new(),
+            traits: Vec::new(),
+            type_vars: Vec::new(),
+        };
+
+        for builtin_type in BuiltinType::iter() {
+            type_arena.intern(DataType::Builtin(builtin_type));
+        }
+
+        type_arena
+    }
+
+    pub fn intern(&mut self, data_type: DataType) -> DataTypeId {
+        if let Some(&id) = self.interned_types.get(&data_type) {
+            return id;
         }
+
+        let id = DataTypeId(self.data_types.len());
+        self.data_types.push(data_type.clone());
+        self.interned_types.insert(data_type, id);
+        id
     }
 
     pub fn get(&self, id: DataTypeId) -> &DataType {
@@ -25,4 +66,335 @@ impl TypeArena {
     pub fn builtin_type_id(&self, builtin_type: BuiltinType) -> DataTypeId {
         DataTypeId(builtin_type as usize)
     }
+
+    pub fn range_type_id(&mut self, element_type: DataTypeId) -> DataTypeId {
+        self.intern(DataType::Range(element_type))
+    }
+
+    pub fn list_type_id(&mut self, element_type: DataTypeId) -> DataTypeId {
+        self.intern(DataType::List(element_type))
+    }
+
+    pub fn map_type_id(&mut self, key_type: DataTypeId, value_type: DataTypeId) -> DataTypeId {
+        self.intern(DataType::Map(key_type, value_type))
+    }
+
+    pub fn tuple_type_id(&mut self, element_types: Vec<DataTypeId>) -> DataTypeId {
+        self.intern(DataType::Tuple(element_types))
+    }
+
+    pub fn module_type_id(&mut self, module: ModuleId) -> DataTypeId {
+        self.intern(DataType::Module(module))
+    }
+
+    pub fn function_type_id(&mut self, param_types: Vec<DataTypeId>, return_type: DataTypeId) -> DataTypeId {
+        self.intern(DataType::Function(param_types, return_type))
+    }
+
+    pub fn user_type_id(&mut self, user_type: UserTypeId, type_args: Vec<DataTypeId>) -> DataTypeId {
+        self.intern(DataType::UserDefined(user_type, type_args))
+    }
+
+    pub fn define_user_type(&mut self, user_type: UserTypeDef) -> UserTypeId {
+        self.user_types.push(user_type);
+        UserTypeId(self.user_types.len() - 1)
+    }
+
+    pub fn user_type(&self, id: UserTypeId) -> &UserTypeDef {
+        &self.user_types[id.0]
+    }
+
+    pub fn user_type_mut(&mut self, id: UserTypeId) -> &mut UserTypeDef {
+        &mut self.user_types[id.0]
+    }
+
+    pub fn define_trait(&mut self, trait_def: TraitDef) -> TraitId {
+        self.traits.push(trait_def);
+        TraitId(self.traits.len() - 1)
+    }
+
+    pub fn trait_def(&self, id: TraitId) -> &TraitDef {
+        &self.traits[id.0]
+    }
+
+    pub fn trait_def_mut(&mut self, id: TraitId) -> &mut TraitDef {
+        &mut self.traits[id.0]
+    }
+
+    pub fn user_type_bindings(&self, data_type: DataTypeId) -> Option<(&UserTypeDef, HashMap<DefaultSymbol, DataTypeId>)> {
+        match self.get(data_type) {
+            DataType::UserDefined(user_type, type_args) => {
+                let user_type = self.user_type(*user_type);
+                let bindings = user_type.type_params.iter().map(|type_param| type_param.name).zip(type_args.iter().copied()).collect();
+                Some((user_type, bindings))
+            },
+            _ => None,
+        }
+    }
+
+    pub fn type_param_id(&mut self, name: DefaultSymbol) -> DataTypeId {
+        self.intern(DataType::TypeParam(name))
+    }
+
+    pub fn fresh_type_var(&mut self) -> DataTypeId {
+        let index = self.type_vars.len();
+        self.type_vars.push(TypeVarState::default());
+        self.intern(DataType::TypeVar(index))
+    }
+
+    pub fn type_var_binding(&self, index: usize) -> Option<DataTypeId> {
+        self.type_vars[index].binding
+    }
+
+    pub fn find(&self, data_type: DataTypeId) -> DataTypeId {
+        let mut data_type = data_type;
+
+        while let DataType::TypeVar(index) = self.get(data_type) {
+            match self.type_vars[*index].binding {
+                Some(binding) => data_type = binding,
+                None => break,
+            }
+        }
+
+        data_type
+    }
+
+    pub fn is_unsolved(&self, data_type: DataTypeId) -> bool {
+        matches!(self.get(self.find(data_type)), DataType::TypeVar(_))
+    }
+
+    pub fn resolve(&mut self, data_type: DataTypeId) -> DataTypeId {
+        self.substitute(data_type, &HashMap::new())
+    }
+
+    fn child_types(&self, data_type: DataTypeId) -> Vec<DataTypeId> {
+        match self.get(data_type) {
+            DataType::Builtin(_) | DataType::TypeParam(_) | DataType::Module(_) => Vec::new(),
+            DataType::TypeVar(index) => self.type_vars[*index].binding.into_iter().collect(),
+            DataType::Range(element_type) | DataType::List(element_type) => vec![*element_type],
+            DataType::Map(key_type, value_type) => vec![*key_type, *value_type],
+            DataType::Tuple(element_types) | DataType::UserDefined(_, element_types) => element_types.clone(),
+            DataType::Function(param_types, return_type) => {
+                param_types.iter().copied().chain(std::iter::once(*return_type)).collect()
+            },
+        }
+    }
+
+    pub fn contains_unsolved(&self, data_type: DataTypeId) -> bool {
+        self.is_unsolved(data_type)
+            || self.child_types(data_type).into_iter().any(|child| self.contains_unsolved(child))
+    }
+
+    pub fn map_key_types(&self, data_type: DataTypeId) -> Vec<DataTypeId> {
+        let mut key_types = match self.get(data_type) {
+            &DataType::Map(key_type, _) => vec![key_type],
+            _ => Vec::new(),
+        };
+
+        for child in self.child_types(data_type) {
+            key_types.extend(self.map_key_types(child));
+        }
+
+        key_types
+    }
+
+    fn occurs(&self, index: usize, data_type: DataTypeId) -> bool {
+        matches!(self.get(data_type), DataType::TypeVar(other) if *other == index)
+            || self.child_types(data_type).into_iter().any(|child| self.occurs(index, child))
+    }
+
+    /// The constraint site where the first type variable inside `data_type` was solved, if any.
+    pub fn binding_site(&self, data_type: DataTypeId) -> Option<SourceSpan> {
+        if let DataType::TypeVar(index) = self.get(data_type) && let Some(bound_at) = self.type_vars[*index].bound_at {
+            return Some(bound_at);
+        }
+
+        self.child_types(data_type).into_iter().find_map(|child| self.binding_site(child))
+    }
+
+    fn unify_all(&mut self, lhs: &[DataTypeId], rhs: &[DataTypeId], span: SourceSpan) -> bool {
+        lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(&lhs, &rhs)| self.unify(lhs, rhs, span))
+    }
+
+    /// Makes `lhs` and `rhs` the same type by solving type variables, recording `span` as the
+    /// constraint site of each variable solved. Returns false if the types conflict.
+    pub fn unify(&mut self, lhs: DataTypeId, rhs: DataTypeId, span: SourceSpan) -> bool {
+        let lhs = self.find(lhs);
+        let rhs = self.find(rhs);
+
+        if lhs == rhs {
+            return true;
+        }
+
+        match (self.get(lhs).clone(), self.get(rhs).clone()) {
+            (DataType::TypeVar(index), _) | (_, DataType::TypeVar(index)) => {
+                let solution = if matches!(self.get(lhs), DataType::TypeVar(other) if *other == index) { rhs } else { lhs };
+
+                if self.occurs(index, solution) {
+                    return false;
+                }
+
+                self.type_vars[index] = TypeVarState {
+                    binding: Some(solution),
+                    bound_at: Some(span),
+                };
+                true
+            },
+
+            (DataType::Range(lhs_element), DataType::Range(rhs_element))
+            | (DataType::List(lhs_element), DataType::List(rhs_element)) => {
+                self.unify(lhs_element, rhs_element, span)
+            },
+
+            (DataType::Map(lhs_key, lhs_value), DataType::Map(rhs_key, rhs_value)) => {
+                self.unify(lhs_key, rhs_key, span) && self.unify(lhs_value, rhs_value, span)
+            },
+
+            (DataType::Tuple(lhs_elements), DataType::Tuple(rhs_elements)) => {
+                self.unify_all(&lhs_elements, &rhs_elements, span)
+            },
+
+            (DataType::UserDefined(lhs_user_type, lhs_args), DataType::UserDefined(rhs_user_type, rhs_args)) => {
+                lhs_user_type == rhs_user_type && self.unify_all(&lhs_args, &rhs_args, span)
+            },
+
+            (DataType::Function(lhs_params, lhs_return), DataType::Function(rhs_params, rhs_return)) => {
+                self.unify_all(&lhs_params, &rhs_params, span) && self.unify(lhs_return, rhs_return, span)
+            },
+
+            _ => false,
+        }
+    }
+
+    pub fn substitute(&mut self, data_type: DataTypeId, bindings: &HashMap<DefaultSymbol, DataTypeId>) -> DataTypeId {
+        match self.get(data_type).clone() {
+            DataType::TypeParam(name) => bindings.get(&name).copied().unwrap_or(data_type),
+
+            DataType::TypeVar(index) => match self.type_vars[index].binding {
+                Some(binding) => self.substitute(binding, bindings),
+                None => data_type,
+            },
+
+            DataType::Builtin(_) | DataType::Module(_) => data_type,
+
+            DataType::UserDefined(user_type, type_args) => {
+                let type_args = type_args
+                    .into_iter()
+                    .map(|type_arg| self.substitute(type_arg, bindings))
+                    .collect();
+                self.user_type_id(user_type, type_args)
+            },
+
+            DataType::Range(element_type) => {
+                let element_type = self.substitute(element_type, bindings);
+                self.range_type_id(element_type)
+            },
+
+            DataType::List(element_type) => {
+                let element_type = self.substitute(element_type, bindings);
+                self.list_type_id(element_type)
+            },
+
+            DataType::Map(key_type, value_type) => {
+                let key_type = self.substitute(key_type, bindings);
+                let value_type = self.substitute(value_type, bindings);
+                self.map_type_id(key_type, value_type)
+            },
+
+            DataType::Tuple(element_types) => {
+                let element_types = element_types
+                    .into_iter()
+                    .map(|element_type| self.substitute(element_type, bindings))
+                    .collect();
+                self.tuple_type_id(element_types)
+            },
+
+            DataType::Function(param_types, return_type) => {
+                let param_types = param_types
+                    .into_iter()
+                    .map(|param_type| self.substitute(param_type, bindings))
+                    .collect();
+                let return_type = self.substitute(return_type, bindings);
+                self.function_type_id(param_types, return_type)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(line_index: usize) -> SourceSpan {
+        SourceSpan::new(line_index, 0, 1)
+    }
+
+    #[test]
+    fn unify_solves_type_variables() {
+        let mut arena = TypeArena::new();
+        let int = arena.builtin_type_id(BuiltinType::Int);
+        let var = arena.fresh_type_var();
+        let list_of_var = arena.list_type_id(var);
+        let list_of_int = arena.list_type_id(int);
+
+        assert!(arena.unify(list_of_var, list_of_int, span(0)));
+        assert_eq!(arena.find(var), int);
+        assert_eq!(arena.resolve(list_of_var), list_of_int);
+    }
+
+    #[test]
+    fn unify_follows_chains_of_variables() {
+        let mut arena = TypeArena::new();
+        let string = arena.builtin_type_id(BuiltinType::String);
+        let first = arena.fresh_type_var();
+        let second = arena.fresh_type_var();
+
+        assert!(arena.unify(first, second, span(0)));
+        assert!(arena.unify(second, string, span(1)));
+        assert_eq!(arena.find(first), string);
+        assert!(!arena.is_unsolved(first));
+    }
+
+    #[test]
+    fn unify_rejects_conflicting_types() {
+        let mut arena = TypeArena::new();
+        let int = arena.builtin_type_id(BuiltinType::Int);
+        let string = arena.builtin_type_id(BuiltinType::String);
+        let var = arena.fresh_type_var();
+        let pair = arena.tuple_type_id(vec![var, var]);
+        let mixed_pair = arena.tuple_type_id(vec![int, string]);
+
+        assert!(!arena.unify(int, string, span(0)));
+        assert!(!arena.unify(pair, mixed_pair, span(0)));
+
+        let short = arena.tuple_type_id(vec![int]);
+        assert!(!arena.unify(short, mixed_pair, span(0)));
+    }
+
+    #[test]
+    fn unify_rejects_infinite_types() {
+        let mut arena = TypeArena::new();
+        let var = arena.fresh_type_var();
+        let list_of_var = arena.list_type_id(var);
+
+        assert!(!arena.unify(var, list_of_var, span(0)));
+        assert!(arena.is_unsolved(var));
+    }
+
+    #[test]
+    fn binding_site_is_where_a_variable_was_first_solved() {
+        let mut arena = TypeArena::new();
+        let int = arena.builtin_type_id(BuiltinType::Int);
+        let var = arena.fresh_type_var();
+        let list_of_var = arena.list_type_id(var);
+
+        assert_eq!(arena.binding_site(list_of_var), None);
+
+        assert!(arena.unify(var, int, span(3)));
+        assert!(arena.unify(var, int, span(5)));
+
+        assert_eq!(arena.binding_site(var), Some(span(3)));
+        assert_eq!(arena.binding_site(list_of_var), Some(span(3)));
+        assert_eq!(arena.binding_site(int), None);
+    }
 }
@@ -1,7 +1,8 @@
 use thiserror::Error;
-use crate::error::compiler_error::CompilerError::{NoInputFiles, FileRead, Spanned};
+use crate::error::compiler_error::CompilerError::{NoInputFiles, FileRead, Project, Spanned};
 use crate::error::spanned_error::{SpannableError, SpannedError};
 use crate::lexer::error::LexerError;
+use crate::project::error::ProjectError;
 use crate::semantic::error::SemanticError;
 use crate::source::source_file::SourceFile;
 use crate::syntax::error::SyntaxError;
@@ -17,6 +18,8 @@ pub enum CompilerError {
     },
 
     Spanned(SourceFile, #[source] SpannedError),
+
+    Project(#[source] ProjectError),
 }
 
 impl std::fmt::Display for CompilerError {
@@ -27,6 +30,7 @@ impl std::fmt::Display for CompilerError {
                 write!(f, "Error: {file_name}: {error}")
             }
             Spanned(file, e) => write!(f, "{}", e.format(file)),
+            Project(e) => write!(f, "{e}"),
         }
     }
 }
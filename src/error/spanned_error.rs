@@ -7,17 +7,29 @@ use thiserror::Error;
 pub struct SpannedError {
     pub error_type: Box<dyn SpannableError>,
     pub span: SourceSpan,
+    pub notes: Vec<(String, SourceSpan)>,
 }
 
 impl SpannedError {
     fn new(error_type: Box<dyn SpannableError>, span: SourceSpan) -> Self {
         Self {
-            error_type, span
+            error_type, span, notes: Vec::new()
         }
     }
 
+    pub fn with_note(mut self, message: String, span: SourceSpan) -> Self {
+        self.notes.push((message, span));
+        self
+    }
+
     pub fn format(&self, source_file: &SourceFile) -> String {
-        format!("{}\n{}", self, self.span.format_source_span(source_file))
+        let mut formatted = format!("{}\n{}", self, self.span.format_source_span(source_file));
+
+        for (message, span) in &self.notes {
+            formatted.push_str(&format!("\n{}\n{}", message, span.format_source_span(source_file)));
+        }
+
+        formatted
     }
 }
 
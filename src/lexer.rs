@@ -1,3 +1,5 @@
 pub mod tokenizer;
 pub mod token;
 pub mod error;
+pub mod string_literal;
+pub mod int_literal;
@@ -7,6 +7,39 @@ pub enum LexerError {
 
     #[error("Error: Unaligned Indent: Indent size {0} is not a multiple of 4")]
     UnalignedIndent(usize),
+
+    #[error("Error: Integer literal {0} is out of range for Int, whose largest value is {max}", max = i64::MAX)]
+    IntLiteralOutOfRange(String),
+
+    #[error("Error: Invalid digit '{digit}' in {base} literal")]
+    InvalidDigit {
+        digit: char,
+        base: &'static str,
+    },
+
+    #[error("Error: Expected digits after '{0}'")]
+    MissingDigits(String),
+
+    #[error("Error: Unterminated multi-line string: expected closing '\"\"\"'")]
+    UnterminatedString,
+
+    #[error("Error: Unknown escape sequence '{0}'")]
+    UnknownEscape(String),
+
+    #[error("Error: Malformed unicode escape: expected '\\u{{' followed by 1 to 6 hex digits and '}}'")]
+    MalformedUnicodeEscape,
+
+    #[error("Error: Escape sequence '{0}' is not a valid unicode scalar value")]
+    InvalidCodePoint(String),
+
+    #[error("Error: Unterminated interpolation: expected '}}'")]
+    UnterminatedInterpolation,
+
+    #[error("Error: Empty interpolation: expected an expression between the braces")]
+    EmptyInterpolation,
+
+    #[error("Error: Unmatched '}}' in string literal; write '\\}}' for a literal brace")]
+    UnmatchedBrace,
 }
 
 
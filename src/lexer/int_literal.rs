@@ -0,0 +1,108 @@
+use crate::error::spanned_error::SpannableError;
+use crate::lexer::error::LexerError::{IntLiteralOutOfRange, InvalidDigit, MissingDigits};
+use crate::lexer::error::LexerResult;
+use crate::source::source_span::SourceSpan;
+
+const PREFIX_LEN: usize = 2;
+
+/// The magnitude of the most negative Int, which is only in range when the literal is negated.
+pub const MAX_INT_MAGNITUDE: u64 = i64::MIN.unsigned_abs();
+
+fn literal_base(literal: &str) -> Option<(u32, &'static str)> {
+    Some(match literal.get(..PREFIX_LEN)? {
+        "0x" | "0X" => (16, "hexadecimal"),
+        "0o" | "0O" => (8, "octal"),
+        "0b" | "0B" => (2, "binary"),
+        _ => return None,
+    })
+}
+
+pub fn parse_int_literal(literal: &str, span: SourceSpan) -> LexerResult<u64> {
+    let (radix, base, digits_start) = match literal_base(literal) {
+        Some((radix, base)) => (radix, base, PREFIX_LEN),
+        None => (10, "decimal", 0),
+    };
+
+    let mut value: Option<u64> = None;
+
+    for (index, c) in literal[digits_start..].char_indices() {
+        if c == '_' {
+            continue;
+        }
+
+        let digit_start = span.start + digits_start + index;
+
+        let digit = c
+            .to_digit(radix)
+            .ok_or_else(|| InvalidDigit { digit: c, base }.at(SourceSpan::new(span.line_index, digit_start, digit_start + c.len_utf8())))?;
+
+        let next_value = value
+            .unwrap_or(0)
+            .checked_mul(radix as u64)
+            .and_then(|value| value.checked_add(digit as u64))
+            .filter(|&value| value <= MAX_INT_MAGNITUDE)
+            .ok_or_else(|| IntLiteralOutOfRange(literal.to_string()).at(span))?;
+
+        value = Some(next_value);
+    }
+
+    value.ok_or_else(|| MissingDigits(literal[..digits_start].to_string()).at(span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(literal: &str) -> LexerResult<u64> {
+        parse_int_literal(literal, SourceSpan::new(0, 0, literal.len()))
+    }
+
+    fn parse_error(literal: &str) -> (String, SourceSpan) {
+        let error = parse(literal).expect_err("literal should be rejected");
+        (error.to_string(), error.span)
+    }
+
+    #[test]
+    fn parses_each_base() {
+        assert_eq!(parse("42").unwrap(), 42);
+        assert_eq!(parse("0x2A").unwrap(), 42);
+        assert_eq!(parse("0X2a").unwrap(), 42);
+        assert_eq!(parse("0o52").unwrap(), 42);
+        assert_eq!(parse("0b101010").unwrap(), 42);
+        assert_eq!(parse("1_000_000").unwrap(), 1_000_000);
+        assert_eq!(parse("0xFF_FF").unwrap(), 0xFFFF);
+    }
+
+    #[test]
+    fn accepts_the_magnitude_of_the_most_negative_int() {
+        assert_eq!(parse("9223372036854775807").unwrap(), i64::MAX as u64);
+        assert_eq!(parse("9223372036854775808").unwrap(), MAX_INT_MAGNITUDE);
+        assert_eq!(parse("0x8000000000000000").unwrap(), MAX_INT_MAGNITUDE);
+    }
+
+    #[test]
+    fn rejects_literals_out_of_range() {
+        for literal in ["9223372036854775809", "99999999999999999999", "0xFFFFFFFFFFFFFFFF", "0x10000000000000000"] {
+            let (message, span) = parse_error(literal);
+
+            assert_eq!(message, format!("Error: Integer literal {literal} is out of range for Int, whose largest value is 9223372036854775807"));
+            assert_eq!(span, SourceSpan::new(0, 0, literal.len()));
+        }
+    }
+
+    #[test]
+    fn reports_invalid_digits_at_the_digit() {
+        let (message, span) = parse_error("0b1021");
+        assert_eq!(message, "Error: Invalid digit '2' in binary literal");
+        assert_eq!(span, SourceSpan::new(0, 4, 5));
+
+        let (message, _) = parse_error("0o78");
+        assert_eq!(message, "Error: Invalid digit '8' in octal literal");
+    }
+
+    #[test]
+    fn requires_digits_after_a_prefix() {
+        assert_eq!(parse_error("0x").0, "Error: Expected digits after '0x'");
+        assert_eq!(parse_error("0b__").0, "Error: Expected digits after '0b'");
+    }
+}
@@ -0,0 +1,277 @@
+use crate::error::spanned_error::SpannableError;
+use crate::lexer::error::LexerError::{
+    EmptyInterpolation, InvalidCodePoint, MalformedUnicodeEscape, UnknownEscape, UnmatchedBrace, UnterminatedInterpolation,
+};
+use crate::lexer::error::LexerResult;
+use crate::source::source_span::SourceSpan;
+
+const MAX_UNICODE_ESCAPE_DIGITS: usize = 6;
+
+pub const MULTI_LINE_QUOTES: &str = "\"\"\"";
+
+fn decode_unicode_escape(contents: &str, u_index: usize, escape_span: impl Fn(usize) -> SourceSpan) -> LexerResult<(char, usize)> {
+    let rest = &contents[u_index + 1..];
+
+    let digits = rest
+        .strip_prefix('{')
+        .and_then(|rest| rest.split_once('}'))
+        .map(|(digits, _)| digits)
+        .filter(|digits| (1..=MAX_UNICODE_ESCAPE_DIGITS).contains(&digits.len()))
+        .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let digits = match digits {
+        Some(digits) => digits,
+        None => return Err(MalformedUnicodeEscape.at(escape_span(1))),
+    };
+
+    // 'u', '{', the digits and '}'
+    let escape_len = digits.len() + 3;
+
+    u32::from_str_radix(digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .map(|c| (c, escape_len))
+        .ok_or_else(|| InvalidCodePoint(format!("\\u{{{digits}}}")).at(escape_span(escape_len)))
+}
+
+#[derive(Debug)]
+pub enum StringSegment<'a> {
+    Text {
+        raw: String,
+        value: String,
+        span: SourceSpan,
+    },
+    Expression {
+        source: &'a str,
+        span: SourceSpan,
+    },
+}
+
+fn find_interpolation_end(contents: &str, open_index: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, c) in contents[open_index..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return Some(open_index + index),
+            '}' => depth -= 1,
+            _ => {},
+        }
+    }
+
+    None
+}
+
+fn decode_contents(contents: &str, line_index: usize, content_start: usize) -> LexerResult<Vec<StringSegment<'_>>> {
+    let content_span = |start: usize, end: usize| SourceSpan::new(line_index, content_start + start, content_start + end);
+
+    let mut segments = Vec::new();
+    let mut value = String::with_capacity(contents.len());
+    let mut text_start = 0;
+    let mut chars = contents.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '{' => {
+                let end = find_interpolation_end(contents, index)
+                    .ok_or_else(|| UnterminatedInterpolation.at(content_span(index, index + 1)))?;
+
+                let source = &contents[index + 1..end];
+
+                if source.trim().is_empty() {
+                    return Err(EmptyInterpolation.at(content_span(index, end + 1)));
+                }
+
+                if segments.is_empty() || !value.is_empty() {
+                    segments.push(StringSegment::Text {
+                        raw: contents[text_start..index].to_string(),
+                        value: std::mem::take(&mut value),
+                        span: content_span(text_start, index),
+                    });
+                }
+
+                segments.push(StringSegment::Expression {
+                    source,
+                    span: content_span(index + 1, end),
+                });
+
+                // Skip the expression and its closing brace
+                while chars.next().is_some_and(|(index, _)| index < end) {}
+                text_start = end + 1;
+                continue;
+            },
+            '}' => return Err(UnmatchedBrace.at(content_span(index, index + 1))),
+            '\\' => {},
+            _ => {
+                value.push(c);
+                continue;
+            },
+        }
+
+        // The span of the escape starting at this backslash, `len` bytes after it
+        let escape_span = |len: usize| content_span(index, index + 1 + len);
+
+        // The lexer only produces string literals whose backslashes are followed by a character
+        let (escape_index, escape) = chars.next().expect("Backslash must be followed by a character");
+
+        value.push(match escape {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '\\' => '\\',
+            '"' => '"',
+            '{' => '{',
+            '}' => '}',
+            'u' => {
+                let (decoded, escape_len) = decode_unicode_escape(contents, escape_index, escape_span)?;
+
+                // Skip the remainder of the escape after the 'u'
+                chars.nth(escape_len - 2);
+                decoded
+            },
+            _ => return Err(UnknownEscape(format!("\\{escape}")).at(escape_span(escape.len_utf8()))),
+        });
+    }
+
+    if segments.is_empty() || !value.is_empty() {
+        segments.push(StringSegment::Text {
+            raw: contents[text_start..].to_string(),
+            value,
+            span: content_span(text_start, contents.len()),
+        });
+    }
+
+    Ok(segments)
+}
+
+fn raw_contents(contents: &str, line_index: usize, content_start: usize) -> Vec<StringSegment<'_>> {
+    vec![StringSegment::Text {
+        raw: contents.to_string(),
+        value: contents.to_string(),
+        span: SourceSpan::new(line_index, content_start, content_start + contents.len()),
+    }]
+}
+
+pub fn decode_string_literal(literal: &str, span: SourceSpan) -> LexerResult<Vec<StringSegment<'_>>> {
+    match literal.strip_prefix('r') {
+        Some(raw) => Ok(raw_contents(&raw[1..raw.len() - 1], span.line_index, span.start + 2)),
+        None => decode_contents(&literal[1..literal.len() - 1], span.line_index, span.start + 1),
+    }
+}
+
+pub type MultiLinePiece<'a> = (usize, usize, &'a str);
+
+pub fn decode_multi_line_string<'a>(pieces: &[MultiLinePiece<'a>], is_raw: bool) -> LexerResult<Vec<StringSegment<'a>>> {
+    let mut segments: Vec<StringSegment> = Vec::new();
+
+    for (index, &(line_index, content_start, contents)) in pieces.iter().enumerate() {
+        let line_segments = if is_raw {
+            raw_contents(contents, line_index, content_start)
+        } else {
+            decode_contents(contents, line_index, content_start)?
+        };
+
+        let newline = (index > 0).then(|| StringSegment::Text {
+            raw: "\n".to_string(),
+            value: "\n".to_string(),
+            span: SourceSpan::new(line_index, 0, 0),
+        });
+
+        for segment in newline.into_iter().chain(line_segments) {
+            match (segments.last_mut(), segment) {
+                (Some(StringSegment::Text { raw, value, .. }), StringSegment::Text { raw: next_raw, value: next_value, .. }) => {
+                    raw.push_str(&next_raw);
+                    value.push_str(&next_value);
+                },
+                (_, segment) => segments.push(segment),
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+pub fn find_multi_line_end(text: &str, is_raw: bool) -> Option<usize> {
+    let mut chars = text.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        if text[index..].starts_with(MULTI_LINE_QUOTES) {
+            return Some(index);
+        }
+
+        if c == '\\' && !is_raw {
+            chars.next();
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(literal: &str) -> LexerResult<Vec<StringSegment<'_>>> {
+        decode_string_literal(literal, SourceSpan::new(0, 0, literal.len()))
+    }
+
+    fn decoded_text(literal: &str) -> (String, String) {
+        match decode(literal).expect("literal should decode").as_slice() {
+            [StringSegment::Text { raw, value, .. }] => (raw.clone(), value.clone()),
+            segments => panic!("expected a single text segment, found {segments:?}"),
+        }
+    }
+
+    fn decode_error(literal: &str) -> (String, SourceSpan) {
+        let error = decode(literal).expect_err("literal should be rejected");
+        (error.to_string(), error.span)
+    }
+
+    #[test]
+    fn decodes_simple_escapes() {
+        let (raw, value) = decoded_text(r#""a\nb\tc\\d\"e""#);
+
+        assert_eq!(value, "a\nb\tc\\d\"e");
+        assert_eq!(raw, r#"a\nb\tc\\d\"e"#);
+    }
+
+    #[test]
+    fn decodes_unicode_escapes() {
+        assert_eq!(decoded_text(r#""\u{41}\u{e9}\u{1F600}""#).1, "Aé😀");
+        assert_eq!(decoded_text(r#""\u{000041}""#).1, "A");
+    }
+
+    #[test]
+    fn keeps_escapes_of_raw_strings() {
+        assert_eq!(decoded_text(r#"r"a\nb""#).1, r"a\nb");
+    }
+
+    #[test]
+    fn reports_unknown_escape_at_its_backslash() {
+        let (message, span) = decode_error(r#""ab\qc""#);
+
+        assert_eq!(message, "Error: Unknown escape sequence '\\q'");
+        assert_eq!(span, SourceSpan::new(0, 3, 5));
+    }
+
+    #[test]
+    fn rejects_malformed_unicode_escapes() {
+        for literal in [r#""\u41""#, r#""\u{}""#, r#""\u{1234567}""#, r#""\u{12g}""#, r#""\u{41""#] {
+            let (message, span) = decode_error(literal);
+
+            assert!(message.starts_with("Error: Malformed unicode escape"), "{literal}: {message}");
+            assert_eq!(span.start, 1, "{literal}");
+        }
+    }
+
+    #[test]
+    fn rejects_escapes_that_are_not_unicode_scalar_values() {
+        let (message, span) = decode_error(r#""\u{110000}""#);
+        assert_eq!(message, "Error: Escape sequence '\\u{110000}' is not a valid unicode scalar value");
+        assert_eq!(span, SourceSpan::new(0, 1, 11));
+
+        let (message, _) = decode_error(r#""\u{D800}""#);
+        assert_eq!(message, "Error: Escape sequence '\\u{D800}' is not a valid unicode scalar value");
+    }
+}
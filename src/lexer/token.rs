@@ -10,12 +10,37 @@ pub struct Token {
     pub token_type: TokenType,
     pub symbol: DefaultSymbol,
     pub span: SourceSpan,
+    pub literal: Option<LiteralValue>,
+}
+
+#[derive(Debug, Clone)]
+pub enum LiteralValue {
+    /// The parser negates the magnitude when the literal follows a unary minus.
+    Int(u64),
+    String(Vec<StringPart>),
+}
+
+#[derive(Debug, Clone)]
+pub enum StringPart {
+    Text {
+        raw: DefaultSymbol,
+        value: DefaultSymbol,
+        span: SourceSpan,
+    },
+    Expression(Vec<Token>),
 }
 
 #[derive(Logos, Debug, Copy, Clone, PartialEq)]
 pub enum TokenType {
     #[token("fn")]
     Fn,
+    #[token("return")]
+    Return,
+
+    #[token("let")]
+    Let,
+    #[token("const")]
+    Const,
 
     #[token("if")]
     If,
@@ -31,6 +56,25 @@ pub enum TokenType {
     #[token("while")]
     While,
 
+    #[token("struct")]
+    Struct,
+    #[token("enum")]
+    Enum,
+    #[token("trait")]
+    Trait,
+    #[token("impl")]
+    Impl,
+
+    #[token("as")]
+    As,
+
+    #[token("import")]
+    Import,
+    #[token("from")]
+    From,
+    #[token("pub")]
+    Pub,
+
     #[token("+")]
     Plus,
     #[token("-")]
@@ -106,6 +150,10 @@ pub enum TokenType {
 
     #[token(".")]
     Dot,
+    #[token("..")]
+    DoubleDot,
+    #[token("..=")]
+    DoubleDotEquals,
 
     #[token("(")]
     OpenParen,
@@ -117,16 +165,26 @@ pub enum TokenType {
     #[token("]")]
     CloseBracket,
 
+    #[token("{")]
+    OpenBrace,
+    #[token("}")]
+    CloseBrace,
+
     #[token(":")]
     Colon,
 
     #[token(",")]
     Comma,
 
-    #[regex(r"[0-9]+")]
+    #[regex(r"[0-9][0-9_]*")]
+    #[regex(r"0[xXoObB][0-9a-zA-Z_]*")]
     IntLiteral,
     #[regex(r#""([^"\\]|\\.)*""#)]
+    #[regex(r#"r"[^"]*""#)]
     StringLiteral,
+    #[token(r#"""""#)]
+    #[token(r#"r""""#)]
+    MultiLineStringStart,
 
     #[regex(r"[a-zA-Z_][a-zA-Z0-9_]*")]
     Identifier,
@@ -141,7 +199,14 @@ pub enum TokenType {
 
 impl Token {
     pub fn new(token_type: TokenType, token_str: DefaultSymbol, span: SourceSpan) -> Self {
-        Self { token_type, symbol: token_str, span }
+        Self { token_type, symbol: token_str, span, literal: None }
+    }
+
+    pub fn with_literal(self, literal: LiteralValue) -> Self {
+        Self {
+            literal: Some(literal),
+            ..self
+        }
     }
 
     pub fn is_legal_statement_boundary(&self) -> bool {
@@ -178,11 +243,20 @@ impl std::fmt::Display for TokenType {
             CloseParen => "')'",
             OpenBracket => "'['",
             CloseBracket => "']'",
+            OpenBrace => "'{'",
+            CloseBrace => "'}'",
             Comma => "','",
             In => "'in'",
+            If => "'if'",
+            Else => "'else'",
+            For => "'for'",
+            Fn => "'fn'",
             Greater => "'>'",
             Less => "'<'",
             Colon => "':'",
+            Equals => "'='",
+            Import => "'import'",
+            Pipe => "'|'",
             Indent(_) => "Indent",
             _ => ""
         })
@@ -1,8 +1,10 @@
 use crate::compiler_context::CompilerContext;
 use crate::error::spanned_error::SpannableError;
-use crate::lexer::error::LexerError::{InvalidToken, UnalignedIndent};
+use crate::lexer::error::LexerError::{InvalidToken, UnalignedIndent, UnterminatedString};
+use crate::lexer::string_literal::{decode_multi_line_string, decode_string_literal, find_multi_line_end, StringSegment, MULTI_LINE_QUOTES};
 use crate::lexer::token::TokenType::Indent;
-use crate::lexer::token::{Token, TokenType};
+use crate::lexer::int_literal::parse_int_literal;
+use crate::lexer::token::{LiteralValue, StringPart, Token, TokenType};
 use crate::source::source_file::SourceFile;
 use crate::source::source_span::SourceSpan;
 use logos::Logos;
@@ -46,39 +48,148 @@ fn get_indent_token(
     }
 }
 
-fn tokenize_line(
+fn tokenize_slice(
+    lines: &[&String],
     line_index: usize,
+    offset: usize,
     content: &str,
     ctx: &mut CompilerContext
-) -> LexerResult<LineTokens> {
-    let mut tokens = vec![get_indent_token(line_index, content, ctx)?];
+) -> LexerResult<(LineTokens, usize)> {
+    let mut tokens = Vec::new();
     let mut lexer = TokenType::lexer(content);
 
     while let Some(next_token) = lexer.next() {
         let span = lexer.span();
-        let source_span = SourceSpan::new(line_index, span.start, span.end);
+        let source_span = SourceSpan::new(line_index, offset + span.start, offset + span.end);
 
         let token_type = next_token
             .map_err(|_| InvalidToken(lexer.slice().to_string()).at(source_span))?;
 
-        tokens.push(Token::new(
+        if token_type == TokenType::MultiLineStringStart {
+            let is_raw = lexer.slice().starts_with('r');
+            let (token, last_line, rest_start) = lex_multi_line_string(lines, source_span, is_raw, ctx)?;
+            tokens.push(token);
+
+            let rest = &lines[last_line][rest_start..];
+            let (rest_tokens, last_line) = tokenize_slice(lines, last_line, rest_start, rest, ctx)?;
+            tokens.extend(rest_tokens);
+
+            return Ok((tokens, last_line));
+        }
+
+        let token = Token::new(
             token_type,
             ctx.get_symbol(lexer.slice()),
             source_span
-        ));
+        );
+
+        let token = match token_type {
+            TokenType::StringLiteral => {
+                let segments = decode_string_literal(lexer.slice(), source_span)?;
+                token.with_literal(LiteralValue::String(lex_string_parts(segments, ctx)?))
+            },
+            TokenType::IntLiteral => token.with_literal(LiteralValue::Int(parse_int_literal(lexer.slice(), source_span)?)),
+            _ => token,
+        };
+
+        tokens.push(token);
     }
 
-    Ok(tokens)
+    Ok((tokens, line_index))
+}
+
+fn lex_multi_line_string(
+    lines: &[&String],
+    open_span: SourceSpan,
+    is_raw: bool,
+    ctx: &mut CompilerContext
+) -> LexerResult<(Token, usize, usize)> {
+    let first_line = open_span.line_index;
+    let mut pieces = Vec::new();
+    let (mut line_index, mut content_start) = (first_line, open_span.end);
+
+    let (last_line, content_end) = loop {
+        let line = lines.get(line_index).ok_or_else(|| UnterminatedString.at(open_span))?;
+
+        if let Some(end) = find_multi_line_end(&line[content_start..], is_raw) {
+            pieces.push((line_index, content_start, &line[content_start..content_start + end]));
+            break (line_index, content_start + end);
+        }
+
+        pieces.push((line_index, content_start, &line[content_start..]));
+        (line_index, content_start) = (line_index + 1, 0);
+    };
+
+    let rest_start = content_end + MULTI_LINE_QUOTES.len();
+
+    // The source text of the literal, from its opening quotes to its closing quotes
+    let literal = if last_line == first_line {
+        lines[first_line][open_span.start..rest_start].to_string()
+    } else {
+        std::iter::once(&lines[first_line][open_span.start..])
+            .chain(lines[first_line + 1..last_line].iter().map(|line| line.as_str()))
+            .chain(std::iter::once(&lines[last_line][..rest_start]))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    // The token's span covers the opening line, as spans cannot cross lines
+    let span_end = if last_line == first_line { rest_start } else { lines[first_line].len() };
+    let span = SourceSpan::new(first_line, open_span.start, span_end);
+
+    let string_parts = lex_string_parts(decode_multi_line_string(&pieces, is_raw)?, ctx)?;
+    let token = Token::new(TokenType::StringLiteral, ctx.get_symbol(&literal), span)
+        .with_literal(LiteralValue::String(string_parts));
+
+    Ok((token, last_line, rest_start))
+}
+
+fn lex_string_parts(segments: Vec<StringSegment>, ctx: &mut CompilerContext) -> LexerResult<Vec<StringPart>> {
+    segments
+        .into_iter()
+        .map(|segment| Ok(match segment {
+            StringSegment::Text { raw, value, span } => StringPart::Text {
+                raw: ctx.get_symbol(&raw),
+                value: ctx.get_symbol(&value),
+                span,
+            },
+            StringSegment::Expression { source, span } => {
+                // Embedded expressions cannot contain quotes, so they never open a multi-line string
+                let (tokens, _) = tokenize_slice(&[], span.line_index, span.start, source, ctx)?;
+                StringPart::Expression(tokens)
+            },
+        }))
+        .collect()
+}
+
+fn tokenize_line(
+    lines: &[&String],
+    line_index: usize,
+    ctx: &mut CompilerContext
+) -> LexerResult<(LineTokens, usize)> {
+    let content = lines[line_index];
+    let mut tokens = vec![get_indent_token(line_index, content, ctx)?];
+
+    let (line_tokens, last_line) = tokenize_slice(lines, line_index, 0, content, ctx)?;
+    tokens.extend(line_tokens);
+
+    Ok((tokens, last_line))
 }
 
 pub fn lex_source_file(
     source_file: &SourceFile,
     ctx: &mut CompilerContext
 ) -> LexerResult<TokenizedLines> {
-    let lines = source_file.into_iter()
-        .enumerate()
-        .map(|(i, content)| tokenize_line(i, content, ctx))
-        .collect::<LexerResult<Vec<LineTokens>>>()?;
+    let lines = source_file.into_iter().collect::<Vec<_>>();
+    let mut tokenized_lines = Vec::new();
+    let mut line_index = 0;
+
+    // Lines inside a multi-line string produce no tokens of their own, not even an indent
+    while line_index < lines.len() {
+        let (line_tokens, last_line) = tokenize_line(&lines, line_index, ctx)?;
+        tokenized_lines.push(line_tokens);
+        line_index = last_line + 1;
+    }
 
-    Ok(TokenizedLines(lines))
+    Ok(TokenizedLines(tokenized_lines))
 }
@@ -1,14 +1,11 @@
-use crate::ast::arena_ast::AST;
 use crate::compiler_context::CompilerContext;
-use crate::lexer::tokenizer::lex_source_file;
-use crate::syntax::parser::ast_parser::ASTParser;
-use error::compiler_error::CompilerError::NoInputFiles;
-use error::compiler_error::CompilerResult;
-use crate::error::compiler_error::CompilerError;
-use crate::error::compiler_error::CompilerError::FileRead;
-use crate::error::spanned_error::SpannedError;
-use crate::semantic::type_synthesis::type_synthesizer::TypeSynthesizer;
-use crate::source::source_file::SourceFile;
+use std::path::Path;
+use crate::error::compiler_error::CompilerError::{FileRead, NoInputFiles, Project};
+use crate::error::compiler_error::CompilerResult;
+use crate::module_loader::ModuleLoader;
+use crate::project::commands::{build_project, new_project};
+use crate::project::error::ProjectError::MissingProjectName;
+use crate::semantic::prelude::register_prelude;
 
 mod lexer;
 mod syntax;
@@ -19,19 +16,8 @@ mod ast;
 mod compiler_context;
 mod types;
 mod operators;
-
-fn compile_source_file(source_file: &SourceFile, compiler_context: &mut CompilerContext) -> Result<(), SpannedError> {
-
-    let source_lines = lex_source_file(source_file, compiler_context)?;
-
-    let ast: AST = ASTParser::generate_ast(source_lines)?;
-
-    let ast = TypeSynthesizer::compute_ast_types(ast, compiler_context);
-
-    println!("{:?}", ast);
-
-    Ok(())
-}
+mod module_loader;
+mod project;
 
 fn compile_program(args: Vec<String>, compiler_context: &mut CompilerContext) -> CompilerResult {
     const MIN_ARG_COUNT: usize = 2;
@@ -40,12 +26,25 @@ fn compile_program(args: Vec<String>, compiler_context: &mut CompilerContext) ->
         return Err(NoInputFiles)
     }
 
+    match args[1].as_str() {
+        "build" => {
+            let project_dir = args.get(2).map_or(".", String::as_str);
+            return build_project(Path::new(project_dir), compiler_context);
+        },
+        "new" => {
+            let name = args.get(2).ok_or(Project(MissingProjectName))?;
+            return new_project(name).map_err(Project);
+        },
+        _ => {},
+    }
+
+    let mut module_loader = ModuleLoader::new(compiler_context);
+
     for source_file_name in args.into_iter().skip(1) {
-        let source_file = SourceFile::read(source_file_name.clone())
+        let source_file_path = std::fs::canonicalize(&source_file_name)
             .map_err(|err| FileRead { file_name: source_file_name, error: err })?;
 
-        compile_source_file(&source_file, compiler_context)
-            .map_err(|spanned_error| CompilerError::Spanned(source_file, spanned_error))?;
+        module_loader.load(&source_file_path)?;
     }
 
     Ok(())
@@ -54,6 +53,7 @@ fn compile_program(args: Vec<String>, compiler_context: &mut CompilerContext) ->
 fn main()  {
     let args = std::env::args().collect::<Vec<_>>();
     let mut compiler_context = CompilerContext::new();
+    register_prelude(&mut compiler_context);
 
     // compile_program(args, &mut compiler_context).unwrap();
 
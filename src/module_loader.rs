@@ -0,0 +1,281 @@
+use std::path::{Path, PathBuf};
+use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::{ASTNodeId, AST};
+use crate::ast::ast_node::ASTNodeType;
+use crate::compiler_context::module::{Module, ModuleId};
+use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::CompilerContext;
+use crate::error::compiler_error::CompilerError;
+use crate::error::compiler_error::CompilerError::{FileRead, Spanned};
+use crate::error::spanned_error::{SpannableError, SpannedError};
+use crate::lexer::tokenizer::lex_source_file;
+use crate::semantic::error::SemanticError::{ImportCycle, UnresolvedModule};
+use crate::semantic::type_synthesis::type_synthesizer::TypeSynthesizer;
+use crate::source::source_file::SourceFile;
+use crate::syntax::parser::ast_parser::ASTParser;
+
+pub struct ModuleLoader<'a> {
+    ctx: &'a mut CompilerContext,
+    /// The files whose imports are being loaded, innermost last, used to detect import cycles.
+    loading: Vec<PathBuf>,
+    search_dirs: Vec<PathBuf>,
+}
+
+impl<'a> ModuleLoader<'a> {
+    pub fn new(ctx: &'a mut CompilerContext) -> Self {
+        Self {
+            ctx,
+            loading: Vec::new(),
+            search_dirs: Vec::new(),
+        }
+    }
+
+    pub fn with_search_dirs(mut self, search_dirs: Vec<PathBuf>) -> Self {
+        self.search_dirs = search_dirs;
+        self
+    }
+
+    fn parse_source_file(&mut self, source_file: &SourceFile, module_scope: ScopeId) -> Result<AST, SpannedError> {
+        let source_lines = lex_source_file(source_file, self.ctx)?;
+        ASTParser::generate_ast(source_lines, module_scope, self.ctx)
+    }
+
+    fn module_file_path(&self, importer: &Path, module_path: &[DefaultSymbol]) -> PathBuf {
+        let mut relative_path = module_path
+            .iter()
+            .map(|&segment| self.ctx.get_str(segment).unwrap_or_default())
+            .collect::<PathBuf>();
+
+        if let Some(extension) = importer.extension() {
+            relative_path.set_extension(extension);
+        }
+
+        let beside_importer = importer.parent().unwrap_or(Path::new("")).join(&relative_path);
+
+        if beside_importer.is_file() {
+            return beside_importer;
+        }
+
+        self.search_dirs
+            .iter()
+            .map(|dir| dir.join(&relative_path))
+            .find(|path| path.is_file())
+            .unwrap_or(beside_importer)
+    }
+
+    fn display_module_path(&self, module_path: &[DefaultSymbol]) -> String {
+        module_path
+            .iter()
+            .map(|&segment| self.ctx.get_str(segment).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn display_cycle(&self, cycle_start: usize, path: &Path) -> String {
+        self.loading[cycle_start..]
+            .iter()
+            .map(PathBuf::as_path)
+            .chain(std::iter::once(path))
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+
+    fn load_import(&mut self, ast: &mut AST, import_node: ASTNodeId, importer: &SourceFile) -> Result<(), CompilerError> {
+        let node = ast.lookup(import_node);
+
+        let module_path = match &node.node_data_type {
+            ASTNodeType::Import(import) => import.path.clone(),
+            _ => return Ok(()),
+        };
+
+        let span = node.span;
+        let file_path = self.module_file_path(importer.path(), &module_path);
+
+        let in_importer = |error: SpannedError| Spanned(importer.clone(), error);
+
+        let file_path = match std::fs::canonicalize(&file_path) {
+            Ok(file_path) => file_path,
+            Err(_) => return Err(in_importer(UnresolvedModule {
+                module: self.display_module_path(&module_path),
+                path: file_path.display().to_string(),
+            }.at(span))),
+        };
+
+        if let Some(cycle_start) = self.loading.iter().position(|loading| *loading == file_path) {
+            return Err(in_importer(ImportCycle(self.display_cycle(cycle_start, &file_path)).at(span)));
+        }
+
+        let module = self.load(&file_path)?;
+
+        if let ASTNodeType::Import(import) = &mut ast.lookup_mut(import_node).node_data_type {
+            import.module = Some(module);
+        }
+
+        Ok(())
+    }
+
+    fn compile_module(&mut self, path: PathBuf) -> Result<ModuleId, CompilerError> {
+        let source_file = SourceFile::read(path.display().to_string())
+            .map_err(|error| FileRead { file_name: path.display().to_string(), error })?;
+
+        let module_scope = self.ctx.symbol_table.add_module_scope();
+
+        let mut ast = match self.parse_source_file(&source_file, module_scope) {
+            Ok(ast) => ast,
+            Err(error) => return Err(Spanned(source_file, error)),
+        };
+
+        self.loading.push(path.clone());
+
+        for node_id in ast.top_level_nodes() {
+            if let Err(error) = self.load_import(&mut ast, node_id, &source_file) {
+                self.loading.pop();
+                return Err(error);
+            }
+        }
+
+        self.loading.pop();
+
+        let exports = ast.exports().clone();
+
+        if let Err(error) = TypeSynthesizer::compute_ast_types(ast, self.ctx) {
+            return Err(Spanned(source_file, error));
+        }
+
+        let name = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+        let name = self.ctx.get_symbol(name);
+
+        Ok(self.ctx.modules.add_module(Module::new(name, path, module_scope, exports)))
+    }
+
+    pub fn load(&mut self, path: &Path) -> Result<ModuleId, CompilerError> {
+        match self.ctx.modules.find(path) {
+            Some(module) => Ok(module),
+            None => self.compile_module(path.to_path_buf()),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::semantic::prelude::register_prelude;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_TEST_DIR: AtomicUsize = AtomicUsize::new(0);
+
+    pub fn compile_files(files: &[(&str, &str)]) -> Result<(), String> {
+        let test_dir = std::env::temp_dir().join(format!(
+            "plc-test-{}-{}",
+            std::process::id(),
+            NEXT_TEST_DIR.fetch_add(1, Ordering::Relaxed),
+        ));
+
+        for (name, source) in files {
+            let path = test_dir.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, source).unwrap();
+        }
+
+        let entry = std::fs::canonicalize(test_dir.join(files[0].0)).unwrap();
+
+        let mut ctx = CompilerContext::new();
+        register_prelude(&mut ctx);
+        let result = ModuleLoader::new(&mut ctx).load(&entry).map(|_| ()).map_err(|error| error.to_string());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+        result
+    }
+
+    pub fn compile(source: &str) -> Result<(), String> {
+        compile_files(&[("main.txt", source)])
+    }
+
+    pub fn compile_files_error(files: &[(&str, &str)]) -> String {
+        let error = compile_files(files).expect_err("compilation should fail");
+        error.lines().next().unwrap_or_default().to_string()
+    }
+
+    pub fn compile_error(source: &str) -> String {
+        compile_files_error(&[("main.txt", source)])
+    }
+
+    #[test]
+    fn compiles_imported_modules() {
+        let files = [
+            ("main.txt", "import geo.vec\nfrom geo.vec import Vec2\n\np = vec.add(Vec2(1, 2), Vec2(3, 4))\nx = p.x\n"),
+            ("geo/vec.txt", "pub struct Vec2\n    x: Int\n    y: Int\n\npub fn add(a: Vec2, b: Vec2): Vec2\n    return Vec2(a.x + b.x, a.y + b.y)\n"),
+        ];
+
+        assert_eq!(compile_files(&files), Ok(()));
+    }
+
+    #[test]
+    fn reports_import_cycles() {
+        let files = [
+            ("main.txt", "import a\n"),
+            ("a.txt", "import b\n"),
+            ("b.txt", "import a\n"),
+        ];
+
+        let error = compile_files_error(&files);
+
+        assert!(error.starts_with("Error: Import cycle "), "{error}");
+        assert_eq!(error.matches("a.txt").count(), 2, "{error}");
+    }
+
+    #[test]
+    fn reports_a_module_importing_itself() {
+        let error = compile_files_error(&[("main.txt", "import main\n")]);
+
+        assert!(error.starts_with("Error: Import cycle "), "{error}");
+    }
+
+    #[test]
+    fn loads_a_module_imported_twice_once() {
+        let files = [
+            ("main.txt", "import a\nimport b\nx = a.f() + b.g()\n"),
+            ("a.txt", "import c\npub fn f(): Int\n    return c.h()\n"),
+            ("b.txt", "import c\npub fn g(): Int\n    return c.h()\n"),
+            ("c.txt", "pub fn h(): Int\n    return 1\n"),
+        ];
+
+        assert_eq!(compile_files(&files), Ok(()));
+    }
+
+    #[test]
+    fn reports_missing_modules() {
+        let error = compile_files_error(&[("main.txt", "import nowhere\n")]);
+
+        assert!(error.starts_with("Error: Cannot find module 'nowhere' at "), "{error}");
+    }
+
+    #[test]
+    fn rejects_imports_of_private_names() {
+        let files = [
+            ("main.txt", "from a import helper\n"),
+            ("a.txt", "fn helper(): Int\n    return 1\n"),
+        ];
+
+        assert_eq!(compile_files_error(&files), "Error: 'helper' is not public in module a");
+    }
+
+    #[test]
+    fn keeps_private_types_of_different_modules_apart() {
+        let files = [
+            ("main.txt", "import a\nimport b\nx = a.make()\ny = b.label()\n"),
+            ("a.txt", "struct Point\n    x: Int\n\npub fn make(): Int\n    return Point(1).x\n"),
+            ("b.txt", "struct Point\n    name: String\n\npub fn label(): String\n    return Point(\"b\").name\n"),
+        ];
+
+        assert_eq!(compile_files(&files), Ok(()));
+
+        let files = [
+            ("main.txt", "import a\np: Point = a.make()\n"),
+            ("a.txt", "struct Point\n    x: Int\n\npub fn make(): Int\n    return 1\n"),
+        ];
+
+        assert_eq!(compile_files_error(&files), "Error: Unknown type 'Point'");
+    }
+}
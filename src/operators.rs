@@ -1,2 +1,3 @@
 pub mod binary_operators;
 pub mod unary_operators;
+pub mod overloaded_operator;
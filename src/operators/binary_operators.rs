@@ -31,9 +31,93 @@ pub enum BinaryOperator {
     LessOrEqual,
     GreaterThan,
     GreaterOrEqual,
+    In,
 
     LogicalAnd,
     LogicalOr,
 
-    CommaOperator,
+    Range,
+    RangeInclusive,
+}
+
+impl BinaryOperator {
+    pub fn is_assignment(&self) -> bool {
+        use BinaryOperator::*;
+
+        matches!(
+            self,
+            Assign | AddAssign | SubAssign | MulAssign | DivAssign | ModAssign
+            | LeftShiftAssign | RightShiftAssign | AndAssign | XorAssign | OrAssign
+        )
+    }
+
+    pub fn compound_operation(&self) -> Option<BinaryOperator> {
+        use BinaryOperator::*;
+
+        Some(match self {
+            AddAssign => Add,
+            SubAssign => Sub,
+            MulAssign => Mul,
+            DivAssign => Div,
+            ModAssign => Mod,
+            LeftShiftAssign => LeftShift,
+            RightShiftAssign => RightShift,
+            AndAssign => BitAnd,
+            XorAssign => BitXor,
+            OrAssign => BitOr,
+            _ => return None,
+        })
+    }
+
+    pub fn is_overloadable(&self) -> bool {
+        use BinaryOperator::*;
+
+        matches!(
+            self,
+            Add | Sub | Mul | Div | Mod
+            | BitAnd | BitOr | BitXor | LeftShift | RightShift
+            | Equal | NotEquals | LessThan | LessOrEqual | GreaterThan | GreaterOrEqual
+        )
+    }
+}
+
+impl std::fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use BinaryOperator::*;
+
+        f.write_str(match self {
+            Assign => "=",
+            AddAssign => "+=",
+            SubAssign => "-=",
+            MulAssign => "*=",
+            DivAssign => "/=",
+            ModAssign => "%=",
+            LeftShiftAssign => "<<=",
+            RightShiftAssign => ">>=",
+            AndAssign => "&=",
+            XorAssign => "^=",
+            OrAssign => "|=",
+            Add => "+",
+            Sub => "-",
+            Mul => "*",
+            Div => "/",
+            Mod => "%",
+            BitAnd => "&",
+            BitOr => "|",
+            BitXor => "^",
+            LeftShift => "<<",
+            RightShift => ">>",
+            Equal => "==",
+            NotEquals => "!=",
+            LessThan => "<",
+            LessOrEqual => "<=",
+            GreaterThan => ">",
+            GreaterOrEqual => ">=",
+            In => "in",
+            LogicalAnd => "&&",
+            LogicalOr => "||",
+            Range => "..",
+            RangeInclusive => "..=",
+        })
+    }
 }
@@ -0,0 +1,17 @@
+use crate::operators::binary_operators::BinaryOperator;
+use crate::operators::unary_operators::UnaryOperator;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OverloadedOperator {
+    Unary(UnaryOperator),
+    Binary(BinaryOperator),
+}
+
+impl std::fmt::Display for OverloadedOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OverloadedOperator::Unary(operator) => operator.fmt(f),
+            OverloadedOperator::Binary(operator) => operator.fmt(f),
+        }
+    }
+}
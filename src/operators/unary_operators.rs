@@ -8,3 +8,27 @@ pub enum UnaryOperator {
     PostInc,
     PostDec,
 }
+
+impl UnaryOperator {
+    pub fn is_increment(&self) -> bool {
+        matches!(self, UnaryOperator::PreInc | UnaryOperator::PreDec | UnaryOperator::PostInc | UnaryOperator::PostDec)
+    }
+
+    pub fn is_overloadable(&self) -> bool {
+        matches!(self, UnaryOperator::Neg | UnaryOperator::Not | UnaryOperator::BitNot)
+    }
+}
+
+impl std::fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use UnaryOperator::*;
+
+        f.write_str(match self {
+            Neg => "-",
+            Not => "!",
+            BitNot => "~",
+            PreInc | PostInc => "++",
+            PreDec | PostDec => "--",
+        })
+    }
+}
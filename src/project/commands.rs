@@ -0,0 +1,59 @@
+use std::path::Path;
+use crate::compiler_context::CompilerContext;
+use crate::error::compiler_error::CompilerError::{FileRead, Project};
+use crate::error::compiler_error::CompilerResult;
+use crate::module_loader::ModuleLoader;
+use crate::project::error::ProjectError::{Io, ProjectExists, UnsupportedBackend};
+use crate::project::error::ProjectResult;
+use crate::project::manifest::{Manifest, DEFAULT_SOURCE_DIR, MANIFEST_FILE_NAME, SOURCE_FILE_EXTENSION};
+
+const SCAFFOLD_MAIN: &str = "\
+fn main()
+    let greeting = \"Hello, world!\"
+";
+
+pub fn build_project(project_dir: &Path, ctx: &mut CompilerContext) -> CompilerResult {
+    let manifest = Manifest::read(project_dir).map_err(Project)?;
+
+    // No code generation exists yet, so any explicitly requested backend is unsupported.
+    if let Some(backend) = &manifest.backend {
+        return Err(Project(UnsupportedBackend {
+            backend: backend.clone(),
+            output: manifest.output,
+        }));
+    }
+
+    let entry_file = manifest.entry_file().map_err(Project)?;
+    let source_files = manifest.source_files().map_err(Project)?;
+
+    let mut module_loader = ModuleLoader::new(ctx).with_search_dirs(manifest.source_dirs);
+
+    for source_file in std::iter::once(entry_file).chain(source_files) {
+        let source_file = std::fs::canonicalize(&source_file)
+            .map_err(|error| FileRead { file_name: source_file.display().to_string(), error })?;
+
+        module_loader.load(&source_file)?;
+    }
+
+    println!("Checked project '{}'", manifest.name);
+    Ok(())
+}
+
+pub fn new_project(name: &str) -> ProjectResult<()> {
+    let project_dir = Path::new(name);
+
+    if project_dir.exists() {
+        return Err(ProjectExists(project_dir.to_path_buf()));
+    }
+
+    let source_dir = project_dir.join(DEFAULT_SOURCE_DIR);
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents).map_err(|error| Io { path: path.to_path_buf(), error })
+    };
+
+    std::fs::create_dir_all(&source_dir).map_err(|error| Io { path: source_dir.clone(), error })?;
+    write(&project_dir.join(MANIFEST_FILE_NAME), &Manifest::scaffold(name))?;
+    write(&source_dir.join("main").with_extension(SOURCE_FILE_EXTENSION), SCAFFOLD_MAIN)?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProjectError {
+    #[error("Error: Usage: new <project name>")]
+    MissingProjectName,
+
+    #[error("Error: No {manifest} found in {dir}", manifest = crate::project::manifest::MANIFEST_FILE_NAME, dir = .0.display())]
+    ManifestNotFound(PathBuf),
+
+    #[error("Error: {}: {message}", .path.display())]
+    InvalidManifest {
+        path: PathBuf,
+        message: String,
+    },
+
+    #[error("Error: Entry module '{0}' not found in the source directories")]
+    EntryModuleNotFound(String),
+
+    #[error("Error: Cannot build '{output}': target backend '{backend}' is not supported by this compiler")]
+    UnsupportedBackend {
+        backend: String,
+        output: String,
+    },
+
+    #[error("Error: {} already exists", .0.display())]
+    ProjectExists(PathBuf),
+
+    #[error("Error: {}: {error}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+}
+
+pub type ProjectResult<T> = Result<T, ProjectError>;
@@ -0,0 +1,127 @@
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+use crate::project::error::ProjectError::{EntryModuleNotFound, InvalidManifest, Io, ManifestNotFound};
+use crate::project::error::{ProjectError, ProjectResult};
+
+pub const MANIFEST_FILE_NAME: &str = "project.toml";
+pub const SOURCE_FILE_EXTENSION: &str = "lang";
+
+pub const DEFAULT_SOURCE_DIR: &str = "src";
+
+#[derive(Debug)]
+pub struct Manifest {
+    pub name: String,
+    pub entry: String,
+    pub source_dirs: Vec<PathBuf>,
+    pub output: String,
+    pub backend: Option<String>,
+}
+
+fn invalid(path: &Path, message: impl Into<String>) -> ProjectError {
+    InvalidManifest {
+        path: path.to_path_buf(),
+        message: message.into(),
+    }
+}
+
+fn string_field(table: &Table, key: &str, path: &Path) -> ProjectResult<Option<String>> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(invalid(path, format!("'{key}' must be a string"))),
+    }
+}
+
+fn required_string_field(table: &Table, key: &str, path: &Path) -> ProjectResult<String> {
+    string_field(table, key, path)?.ok_or_else(|| invalid(path, format!("missing required field '{key}'")))
+}
+
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>) -> ProjectResult<()> {
+    let entries = std::fs::read_dir(dir).map_err(|error| Io { path: dir.to_path_buf(), error })?;
+
+    for entry in entries {
+        let path = entry.map_err(|error| Io { path: dir.to_path_buf(), error })?.path();
+
+        if path.is_dir() {
+            collect_source_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| extension == SOURCE_FILE_EXTENSION) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+impl Manifest {
+    pub fn read(project_dir: &Path) -> ProjectResult<Self> {
+        let path = project_dir.join(MANIFEST_FILE_NAME);
+
+        if !path.is_file() {
+            return Err(ManifestNotFound(project_dir.to_path_buf()));
+        }
+
+        let contents = std::fs::read_to_string(&path).map_err(|error| Io { path: path.clone(), error })?;
+        Self::parse(project_dir, &path, &contents)
+    }
+
+    fn parse(root: &Path, path: &Path, contents: &str) -> ProjectResult<Self> {
+        let manifest = contents.parse::<Table>().map_err(|error| invalid(path, error.message()))?;
+
+        let project = match manifest.get("project") {
+            Some(Value::Table(project)) => project,
+            _ => return Err(invalid(path, "missing [project] table")),
+        };
+
+        let name = required_string_field(project, "name", path)?;
+        let entry = required_string_field(project, "entry", path)?;
+
+        let source_dirs = match project.get("source_dirs") {
+            None => vec![root.join(DEFAULT_SOURCE_DIR)],
+            Some(Value::Array(dirs)) => dirs
+                .iter()
+                .map(|dir| match dir {
+                    Value::String(dir) => Ok(root.join(dir)),
+                    _ => Err(invalid(path, "'source_dirs' must be a list of strings")),
+                })
+                .collect::<ProjectResult<Vec<_>>>()?,
+            Some(_) => return Err(invalid(path, "'source_dirs' must be a list of strings")),
+        };
+
+        Ok(Self {
+            output: string_field(project, "output", path)?.unwrap_or_else(|| name.clone()),
+            backend: string_field(project, "backend", path)?,
+            name,
+            entry,
+            source_dirs,
+        })
+    }
+
+    pub fn scaffold(name: &str) -> String {
+        let name = Value::String(name.to_string());
+
+        format!(
+            "[project]\nname = {name}\nentry = \"main\"\nsource_dirs = [\"{DEFAULT_SOURCE_DIR}\"]\noutput = {name}\n"
+        )
+    }
+
+    pub fn entry_file(&self) -> ProjectResult<PathBuf> {
+        let relative_path = self.entry.split('.').collect::<PathBuf>().with_extension(SOURCE_FILE_EXTENSION);
+
+        self.source_dirs
+            .iter()
+            .map(|dir| dir.join(&relative_path))
+            .find(|path| path.is_file())
+            .ok_or_else(|| EntryModuleNotFound(self.entry.clone()))
+    }
+
+    pub fn source_files(&self) -> ProjectResult<Vec<PathBuf>> {
+        let mut files = Vec::new();
+
+        for dir in &self.source_dirs {
+            collect_source_files(dir, &mut files)?;
+        }
+
+        files.sort();
+        Ok(files)
+    }
+}
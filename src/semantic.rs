@@ -1,3 +1,6 @@
 pub mod error;
 pub mod type_synthesis;
 mod name_resolution;
+mod type_resolution;
+mod const_evaluation;
+pub mod prelude;
@@ -0,0 +1,205 @@
+use crate::ast::arena_ast::{ASTNodeId, AST};
+use crate::ast::ast_node::ASTNodeType;
+use crate::compiler_context::symbol::Mutability;
+use crate::compiler_context::CompilerContext;
+use crate::error::spanned_error::SpannableError;
+use crate::operators::binary_operators::BinaryOperator;
+use crate::operators::unary_operators::UnaryOperator;
+use crate::semantic::error::SemanticError::{ConstConversion, ConstDivisionByZero, ConstOverflow, NonConstantExpression};
+use crate::semantic::error::SemanticResult;
+use crate::source::source_span::SourceSpan;
+use crate::types::const_value::ConstValue;
+use crate::types::const_value::ConstValue::{Bool, Int};
+use crate::types::data_type::{BuiltinType, DataType};
+
+pub struct ConstEvaluator<'a> {
+    ast: &'a AST,
+    ctx: &'a CompilerContext,
+}
+
+impl<'a> ConstEvaluator<'a> {
+    pub fn new(ast: &'a AST, ctx: &'a CompilerContext) -> Self {
+        Self {
+            ast,
+            ctx
+        }
+    }
+
+    fn literal_str(&self, symbol: string_interner::DefaultSymbol) -> &str {
+        self.ctx.get_str(symbol).expect("Literal must be interned")
+    }
+
+    fn evaluate_unary(&self, op_type: UnaryOperator, operand: ConstValue, span: SourceSpan) -> SemanticResult<ConstValue> {
+        use UnaryOperator::*;
+
+        Ok(match (op_type, operand) {
+            (Neg, Int(value)) => Int(value.checked_neg().ok_or_else(|| ConstOverflow.at(span))?),
+            (Not, Bool(value)) => Bool(!value),
+            (BitNot, Int(value)) => Int(!value),
+            _ => return Err(NonConstantExpression.at(span)),
+        })
+    }
+
+    fn evaluate_int_binary(&self, op_type: BinaryOperator, lhs: i64, rhs: i64, span: SourceSpan) -> SemanticResult<ConstValue> {
+        use BinaryOperator::*;
+
+        if matches!(op_type, Div | Mod) && rhs == 0 {
+            return Err(ConstDivisionByZero.at(span));
+        }
+
+        let shift = || u32::try_from(rhs).ok();
+
+        let value = match op_type {
+            Add => lhs.checked_add(rhs),
+            Sub => lhs.checked_sub(rhs),
+            Mul => lhs.checked_mul(rhs),
+            Div => lhs.checked_div(rhs),
+            Mod => lhs.checked_rem(rhs),
+            BitAnd => Some(lhs & rhs),
+            BitOr => Some(lhs | rhs),
+            BitXor => Some(lhs ^ rhs),
+            LeftShift => shift().and_then(|shift| lhs.checked_shl(shift)),
+            RightShift => shift().and_then(|shift| lhs.checked_shr(shift)),
+            Equal => return Ok(Bool(lhs == rhs)),
+            NotEquals => return Ok(Bool(lhs != rhs)),
+            LessThan => return Ok(Bool(lhs < rhs)),
+            LessOrEqual => return Ok(Bool(lhs <= rhs)),
+            GreaterThan => return Ok(Bool(lhs > rhs)),
+            GreaterOrEqual => return Ok(Bool(lhs >= rhs)),
+            _ => return Err(NonConstantExpression.at(span)),
+        };
+
+        value.map(Int).ok_or_else(|| ConstOverflow.at(span))
+    }
+
+    fn evaluate_binary(&self, op_type: BinaryOperator, lhs: ConstValue, rhs: ConstValue, span: SourceSpan) -> SemanticResult<ConstValue> {
+        use BinaryOperator::*;
+        use ConstValue::String;
+
+        Ok(match (op_type, lhs, rhs) {
+            (_, Int(lhs), Int(rhs)) => return self.evaluate_int_binary(op_type, lhs, rhs, span),
+
+            (Add, String(lhs), String(rhs)) => String(lhs + &rhs),
+            (In, String(lhs), String(rhs)) => Bool(rhs.contains(&lhs)),
+            (LessThan, String(lhs), String(rhs)) => Bool(lhs < rhs),
+            (LessOrEqual, String(lhs), String(rhs)) => Bool(lhs <= rhs),
+            (GreaterThan, String(lhs), String(rhs)) => Bool(lhs > rhs),
+            (GreaterOrEqual, String(lhs), String(rhs)) => Bool(lhs >= rhs),
+
+            (LogicalAnd, Bool(lhs), Bool(rhs)) => Bool(lhs && rhs),
+            (LogicalOr, Bool(lhs), Bool(rhs)) => Bool(lhs || rhs),
+
+            (Equal, lhs, rhs) => Bool(lhs == rhs),
+            (NotEquals, lhs, rhs) => Bool(lhs != rhs),
+
+            _ => return Err(NonConstantExpression.at(span)),
+        })
+    }
+
+    fn evaluate_cast(&self, value: ConstValue, target: BuiltinType, span: SourceSpan) -> SemanticResult<ConstValue> {
+        Ok(match (value, target) {
+            (Int(value), BuiltinType::Int) => Int(value),
+            (Bool(value), BuiltinType::Bool) => Bool(value),
+            (Bool(value), BuiltinType::Int) => Int(value as i64),
+            (ConstValue::String(value), BuiltinType::String) => ConstValue::String(value),
+            (value, BuiltinType::String) => ConstValue::String(value.to_string()),
+
+            (ConstValue::String(value), BuiltinType::Int) => match value.parse() {
+                Ok(value) => Int(value),
+                Err(_) => return Err(ConstConversion {
+                    value: ConstValue::String(value).to_string(),
+                    to: target.to_string(),
+                }.at(span)),
+            },
+
+            _ => return Err(NonConstantExpression.at(span)),
+        })
+    }
+
+    pub fn evaluate(&self, node_id: ASTNodeId) -> SemanticResult<ConstValue> {
+        let node = self.ast.lookup(node_id);
+        let span = node.span;
+
+        match &node.node_data_type {
+            ASTNodeType::IntLiteral(value) => Ok(Int(*value)),
+
+            ASTNodeType::StringLiteral(literal) => Ok(ConstValue::String(self.literal_str(literal.value).to_string())),
+
+            ASTNodeType::Variable(var) => match self.ctx.symbol_table.lookup(var.name, node.scope) {
+                Some(symbol) => match &symbol.mutability {
+                    Mutability::Const(value) => Ok(value.clone()),
+                    _ => Err(NonConstantExpression.at(span)),
+                },
+                None => Err(NonConstantExpression.at(span)),
+            },
+
+            ASTNodeType::UnaryOperator(op) => {
+                let operand = self.evaluate(op.operand)?;
+                self.evaluate_unary(op.op_type, operand, span)
+            },
+
+            ASTNodeType::BinaryOperator(op) => {
+                let lhs = self.evaluate(op.left)?;
+                let rhs = self.evaluate(op.right)?;
+                self.evaluate_binary(op.op_type, lhs, rhs, span)
+            },
+
+            ASTNodeType::Cast(cast) => {
+                let value = self.evaluate(cast.operand)?;
+
+                match node.data_type.map(|data_type| self.ctx.type_arena.get(data_type)) {
+                    Some(DataType::Builtin(target)) => self.evaluate_cast(value, *target, span),
+                    _ => Err(NonConstantExpression.at(span)),
+                }
+            },
+
+            ASTNodeType::Conditional(conditional) => match self.evaluate(conditional.condition)? {
+                Bool(true) => self.evaluate(conditional.then_value),
+                Bool(false) => self.evaluate(conditional.else_value),
+                _ => Err(NonConstantExpression.at(span)),
+            },
+
+            _ => Err(NonConstantExpression.at(span)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module_loader::tests::{compile, compile_error};
+
+    #[test]
+    fn evaluates_constants_from_other_constants() {
+        let source = "const LIMIT = 10 * 4 + 2\nconst NAME = \"n\" + (LIMIT as String)\nconst FLAG = 1 if LIMIT > 40 else 0\nx: Int = LIMIT + FLAG\n";
+
+        assert_eq!(compile(source), Ok(()));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        assert_eq!(compile_error("const A = 9223372036854775807 + 1\n"), "Error: Integer overflow in constant expression");
+        assert_eq!(compile_error("const A = -9223372036854775807 - 2\n"), "Error: Integer overflow in constant expression");
+        assert_eq!(compile_error("const A = 4611686018427387904 * 2\n"), "Error: Integer overflow in constant expression");
+    }
+
+    #[test]
+    fn accepts_results_at_the_bounds() {
+        assert_eq!(compile("const A = 9223372036854775806 + 1\nconst B = -9223372036854775807 - 1\n"), Ok(()));
+    }
+
+    #[test]
+    fn reports_division_by_zero() {
+        assert_eq!(compile_error("const A = 1 / 0\n"), "Error: Division by zero in constant expression");
+        assert_eq!(compile_error("const A = 1 % (2 - 2)\n"), "Error: Division by zero in constant expression");
+    }
+
+    #[test]
+    fn reports_failed_conversions() {
+        assert_eq!(compile_error("const A = \"x\" as Int\n"), "Error: Cannot convert \"x\" to Int in constant expression");
+    }
+
+    #[test]
+    fn rejects_values_not_known_at_compile_time() {
+        assert_eq!(compile_error("x = 1\nconst A = x + 1\n"), "Error: Constant value must be computable at compile time");
+    }
+}
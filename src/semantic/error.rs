@@ -1,15 +1,265 @@
 use thiserror::Error;
+use crate::error::spanned_error::SpannedError;
 use crate::operators::binary_operators::BinaryOperator;
 use crate::operators::unary_operators::UnaryOperator;
-use crate::types::data_type::DataType;
 
 #[derive(Error, Debug)]
 pub enum SemanticError {
-    #[error("Error:")]
+    #[error("Error: Operator {0} is not defined for this operand type")]
     MismatchedUnaryOperatorTypes(UnaryOperator),
     
-    #[error("Error:")]
+    #[error("Error: Operator {0} is not defined for these operand types")]
     MismatchedBinaryOperatorTypes(BinaryOperator), // TODO: adding operands types correctly
+
+    #[error("Error: Condition must be of type Bool, found {0}")]
+    NonBoolCondition(String),
+
+    #[error("Error: Condition must be of type Bool, found {found}, compare it against a value, e.g. `{suggestion}`")]
+    NonBoolComparableCondition {
+        found: String,
+        suggestion: &'static str,
+    },
+
+    #[error("Error: Type {0} is not iterable")]
+    NotIterable(String),
+
+    #[error("Error: Cannot destructure {found} into {expected} names")]
+    MismatchedDestructure {
+        expected: usize,
+        found: String,
+    },
+
+    #[error("Error: List elements must all be of type {expected}, found {found}")]
+    MismatchedListElement {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Map keys must all be of type {expected}, found {found}")]
+    MismatchedMapKey {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Map values must all be of type {expected}, found {found}")]
+    MismatchedMapValue {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Type {0} cannot be used as a map key, expected Bool, Int or String")]
+    UnhashableKey(String),
+
+    #[error("Error: Cannot assign to immutable binding '{0}'")]
+    AssignToImmutable(String),
+
+    #[error("Error: Cannot assign to constant '{0}'")]
+    AssignToConst(String),
+
+    #[error("Error: Constant value must be computable at compile time")]
+    NonConstantExpression,
+
+    #[error("Error: Integer overflow in constant expression")]
+    ConstOverflow,
+
+    #[error("Error: Division by zero in constant expression")]
+    ConstDivisionByZero,
+
+    #[error("Error: Cannot convert {value} to {to} in constant expression")]
+    ConstConversion {
+        value: String,
+        to: String,
+    },
+
+    #[error("Error: Both branches of a conditional expression must have type {expected}, found {found}")]
+    MismatchedConditionalBranches {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Cannot cast {from} to {to}")]
+    InvalidCast {
+        from: String,
+        to: String,
+    },
+
+    #[error("Error: Cannot cast {from} to {to}, user-defined types can only be cast to themselves")]
+    UserTypeCast {
+        from: String,
+        to: String,
+    },
+
+    #[error("Error: Values of type {0} cannot be formatted as a string")]
+    NotFormattable(String),
+
+    #[error("Error: Values of type {0} cannot be formatted as a string, give the type a `to_string(self): String` method")]
+    MissingToString(String),
+
+    #[error("Error: Method 'to_string' used to format an interpolated value must return String, found {0}")]
+    InvalidToStringReturn(String),
+
+    #[error("Error: Type {0} cannot be indexed")]
+    NotIndexable(String),
+
+    #[error("Error: Index must be of type {expected}, found {found}")]
+    MismatchedIndexType {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Type {receiver} has no field '{field}'")]
+    UnknownField {
+        receiver: String,
+        field: String,
+    },
+
+    #[error("Error: Type {receiver} has no method '{method}'")]
+    UnknownMethod {
+        receiver: String,
+        method: String,
+    },
+
+    #[error("Error: Expected {expected} argument(s), found {found}")]
+    WrongArgumentCount {
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Error: No argument given for parameter {0}")]
+    MissingArgument(String),
+
+    #[error("Error: Unknown keyword argument '{0}'")]
+    UnknownKeywordArgument(String),
+
+    #[error("Error: Parameter {0} was given more than one argument")]
+    DuplicateArgument(String),
+
+    #[error("Error: Type '{0}' is already defined")]
+    DuplicateType(String),
+
+    #[error("Error: '{0}' is already defined in this scope")]
+    DuplicateDefinition(String),
+
+    #[error("Error: Operator {0} can only be overloaded for operands that include a struct or enum")]
+    BuiltinOperatorOverload(String),
+
+    #[error("Error: Operator {0} cannot be overloaded with type parameters")]
+    GenericOperatorOverload(String),
+
+    #[error("Error: Operator {0} is already defined")]
+    DuplicateOperatorOverload(String),
+
+    #[error("Error: Operator {0} must be defined as a function, not a method")]
+    OperatorMethod(String),
+
+    #[error("Error: Cannot find module '{module}' at {path}")]
+    UnresolvedModule {
+        module: String,
+        path: String,
+    },
+
+    #[error("Error: Import cycle {0}")]
+    ImportCycle(String),
+
+    #[error("Error: '{name}' is not public in module {module}")]
+    NotExported {
+        module: String,
+        name: String,
+    },
+
+    #[error("Error: Module {module} has no member '{name}'")]
+    UnknownModuleMember {
+        module: String,
+        name: String,
+    },
+
+    #[error("Error: Unknown trait '{0}'")]
+    UnknownTrait(String),
+
+    #[error("Error: Trait {trait_name} is already implemented for {type_name}")]
+    DuplicateImpl {
+        trait_name: String,
+        type_name: String,
+    },
+
+    #[error("Error: Type {type_name} does not provide method '{method}' of trait {trait_name}")]
+    MissingTraitMethod {
+        trait_name: String,
+        type_name: String,
+        method: String,
+    },
+
+    #[error("Error: Method '{method}' is not a member of trait {trait_name}")]
+    UnknownTraitMethod {
+        trait_name: String,
+        method: String,
+    },
+
+    #[error("Error: Method '{method}' must have type {expected} to implement trait {trait_name}, found {found}")]
+    MismatchedTraitMethod {
+        trait_name: String,
+        method: String,
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Method '{0}' is already defined")]
+    DuplicateMethod(String),
+
+    #[error("Error: Type {type_name} does not implement trait {trait_name}")]
+    UnsatisfiedBound {
+        type_name: String,
+        trait_name: String,
+    },
+
+    #[error("Error: Method '{0}' must take self as its first parameter")]
+    MissingSelfParameter(String),
+
+    #[error("Error: Cannot infer the type of this expression, found {0}")]
+    AmbiguousType(String),
+
+    #[error("Error: Default value must have type {expected}, found {found}")]
+    MismatchedDefaultValue {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Expected argument of type {expected}, found {found}")]
+    MismatchedArgumentType {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Unknown type '{0}'")]
+    UnknownType(String),
+
+    #[error("Error: Type {type_name} expects {expected} type argument(s), found {found}")]
+    WrongTypeArgumentCount {
+        type_name: String,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Error: Cannot assign a value of type {found} to a target of type {expected}")]
+    MismatchedAssignment {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Type {0} is not callable")]
+    NotCallable(String),
+
+    #[error("Error: Unknown name '{0}'")]
+    UnknownName(String),
+
+    #[error("Error: Function must return {expected}, found {found}")]
+    MismatchedReturnType {
+        expected: String,
+        found: String,
+    },
+
+    #[error("Error: Return statement outside of a function")]
+    ReturnOutsideFunction,
 }
 
-pub type SemanticResult<T> = Result<T, SemanticError>;
+pub type SemanticResult<T> = Result<T, SpannedError>;
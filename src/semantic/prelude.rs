@@ -0,0 +1,111 @@
+use crate::compiler_context::symbol::Symbol;
+use crate::compiler_context::type_arena::DataTypeId;
+use crate::compiler_context::CompilerContext;
+use crate::source::source_span::SourceSpan;
+use crate::types::data_type::BuiltinType;
+use crate::types::function_signature::{FunctionSignature, ParamSignature};
+use crate::types::type_param::TypeParam;
+use BuiltinType::{Bool, Int, String, Unit};
+use PreludeType::{Builtin, Generic, ListOf};
+
+const PRELUDE_SPAN: SourceSpan = SourceSpan {
+    line_index: 0,
+    start: 0,
+    end: 0,
+};
+
+#[derive(Copy, Clone)]
+enum PreludeType {
+    Builtin(BuiltinType),
+    ListOf(BuiltinType),
+    Generic,
+}
+
+type PreludeParam = (&'static str, PreludeType, bool);
+
+struct PreludeFunction {
+    name: &'static str,
+    params: &'static [PreludeParam],
+    return_type: PreludeType,
+}
+
+const fn function(name: &'static str, params: &'static [PreludeParam], return_type: PreludeType) -> PreludeFunction {
+    PreludeFunction {
+        name,
+        params,
+        return_type,
+    }
+}
+
+const PRELUDE_FUNCTIONS: &[PreludeFunction] = &[
+    // Console I/O
+    function("print", &[("value", Generic, false)], Builtin(Unit)),
+    function("input", &[("prompt", Builtin(String), true)], Builtin(String)),
+
+    // String conversion
+    function("str", &[("value", Generic, false)], Builtin(String)),
+    function("int", &[("text", Builtin(String), false)], Builtin(Int)),
+    function("len", &[("text", Builtin(String), false)], Builtin(Int)),
+
+    // Integer math
+    function("abs", &[("value", Builtin(Int), false)], Builtin(Int)),
+    function("min", &[("a", Builtin(Int), false), ("b", Builtin(Int), false)], Builtin(Int)),
+    function("max", &[("a", Builtin(Int), false), ("b", Builtin(Int), false)], Builtin(Int)),
+    function("pow", &[("base", Builtin(Int), false), ("exponent", Builtin(Int), false)], Builtin(Int)),
+
+    // File reading
+    function("read_file", &[("path", Builtin(String), false)], Builtin(String)),
+    function("read_lines", &[("path", Builtin(String), false)], ListOf(String)),
+    function("file_exists", &[("path", Builtin(String), false)], Builtin(Bool)),
+];
+
+const TYPE_PARAM_NAME: &str = "T";
+
+fn resolve_type(prelude_type: PreludeType, ctx: &mut CompilerContext) -> DataTypeId {
+    match prelude_type {
+        Builtin(builtin_type) => ctx.type_arena.builtin_type_id(builtin_type),
+        ListOf(builtin_type) => {
+            let element_type = ctx.type_arena.builtin_type_id(builtin_type);
+            ctx.type_arena.list_type_id(element_type)
+        },
+        Generic => {
+            let name = ctx.get_symbol(TYPE_PARAM_NAME);
+            ctx.type_arena.type_param_id(name)
+        },
+    }
+}
+
+fn resolve_signature(function: &PreludeFunction, ctx: &mut CompilerContext) -> FunctionSignature {
+    let params = function.params
+        .iter()
+        .map(|&(name, param_type, has_default)| {
+            let name = ctx.get_symbol(name);
+            ParamSignature::new(Some(name), resolve_type(param_type, ctx), has_default)
+        })
+        .collect::<Vec<_>>();
+
+    let is_generic = function.params.iter().any(|&(_, param_type, _)| matches!(param_type, Generic));
+
+    let type_params = if is_generic {
+        vec![TypeParam {
+            formattable: true,
+            ..TypeParam::new(ctx.get_symbol(TYPE_PARAM_NAME), Vec::new(), PRELUDE_SPAN)
+        }]
+    } else {
+        Vec::new()
+    };
+
+    FunctionSignature::new(type_params, params, resolve_type(function.return_type, ctx))
+}
+
+pub fn register_prelude(ctx: &mut CompilerContext) {
+    let global_scope = ctx.symbol_table.global_scope();
+
+    for function in PRELUDE_FUNCTIONS {
+        let name = ctx.get_symbol(function.name);
+        let signature = resolve_signature(function, ctx);
+        let function_type = ctx.type_arena.function_type_id(signature.param_types(), signature.return_type);
+
+        ctx.symbol_table.add_symbol(global_scope, Symbol::function(name, function_type, signature, PRELUDE_SPAN));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::symbol::NamedType;
+use crate::compiler_context::type_arena::DataTypeId;
+use crate::compiler_context::CompilerContext;
+use crate::error::spanned_error::SpannableError;
+use crate::semantic::error::SemanticError::{UnhashableKey, UnknownType, WrongTypeArgumentCount};
+use crate::semantic::error::SemanticResult;
+use crate::source::source_span::SourceSpan;
+use crate::types::data_type::BuiltinType;
+use crate::types::type_annotation::TypeAnnotation;
+use crate::types::type_param::TypeParam;
+
+fn expect_type_argument_count(
+    type_name: &str,
+    type_args: &[DataTypeId],
+    expected: usize,
+    span: SourceSpan,
+) -> SemanticResult<()> {
+    if type_args.len() == expected {
+        Ok(())
+    } else {
+        Err(WrongTypeArgumentCount {
+            type_name: type_name.to_string(),
+            expected,
+            found: type_args.len(),
+        }.at(span))
+    }
+}
+
+pub fn resolve_type_annotation(
+    annotation: &TypeAnnotation,
+    type_params: &[TypeParam],
+    scope: ScopeId,
+    ctx: &mut CompilerContext,
+) -> SemanticResult<DataTypeId> {
+    let span = annotation.span;
+
+    let type_args = annotation.inner_types
+        .iter()
+        .map(|inner_type| resolve_type_annotation(inner_type, type_params, scope, ctx))
+        .collect::<SemanticResult<Vec<DataTypeId>>>()?;
+
+    if type_params.iter().any(|type_param| type_param.name == annotation.type_name) {
+        let type_name = ctx.get_str(annotation.type_name).unwrap_or_default().to_string();
+        expect_type_argument_count(&type_name, &type_args, 0, span)?;
+        return Ok(ctx.type_arena.type_param_id(annotation.type_name));
+    }
+
+    let type_name = ctx.get_str(annotation.type_name).unwrap_or_default().to_string();
+
+    let builtin_type = match type_name.as_str() {
+        "Unit" => Some(BuiltinType::Unit),
+        "Bool" => Some(BuiltinType::Bool),
+        "Int" => Some(BuiltinType::Int),
+        "String" => Some(BuiltinType::String),
+        _ => None,
+    };
+
+    if let Some(builtin_type) = builtin_type {
+        expect_type_argument_count(&type_name, &type_args, 0, span)?;
+        return Ok(ctx.type_arena.builtin_type_id(builtin_type));
+    }
+
+    match type_name.as_str() {
+        "Range" => {
+            expect_type_argument_count(&type_name, &type_args, 1, span)?;
+            Ok(ctx.type_arena.range_type_id(type_args[0]))
+        },
+
+        "List" => {
+            expect_type_argument_count(&type_name, &type_args, 1, span)?;
+            Ok(ctx.type_arena.list_type_id(type_args[0]))
+        },
+
+        "Map" => {
+            expect_type_argument_count(&type_name, &type_args, 2, span)?;
+
+            if !ctx.type_arena.get(type_args[0]).is_hashable() {
+                return Err(UnhashableKey(ctx.type_arena.get(type_args[0]).display(ctx)).at(span));
+            }
+
+            Ok(ctx.type_arena.map_type_id(type_args[0], type_args[1]))
+        },
+
+        "Tuple" => Ok(ctx.type_arena.tuple_type_id(type_args)),
+
+        "Fn" => match type_args.split_last() {
+            Some((&return_type, param_types)) => Ok(ctx.type_arena.function_type_id(param_types.to_vec(), return_type)),
+            None => Err(WrongTypeArgumentCount {
+                type_name,
+                expected: 1,
+                found: 0,
+            }.at(span)),
+        },
+
+        _ => match ctx.symbol_table.lookup_type(annotation.type_name, scope) {
+            Some(NamedType::UserType(user_type)) => {
+                let type_param_count = ctx.type_arena.user_type(user_type).type_params.len();
+                expect_type_argument_count(&type_name, &type_args, type_param_count, span)?;
+                Ok(ctx.type_arena.user_type_id(user_type, type_args))
+            },
+            _ => Err(UnknownType(type_name).at(span)),
+        },
+    }
+}
@@ -1,3 +1,6 @@
 pub mod type_synthesizer;
 mod builtin_operator_registry;
-mod operator_registry;
\ No newline at end of file
+mod builtin_casts;
+pub mod operator_registry;
+mod iterable_registry;
+mod method_registry;
@@ -0,0 +1,22 @@
+use crate::compiler_context::type_arena::{DataTypeId, TypeArena};
+use crate::types::data_type::BuiltinType;
+use crate::types::data_type::BuiltinType::{Bool, Int, String};
+use crate::types::data_type::DataType::Builtin;
+
+const BUILTIN_CASTS: &[(BuiltinType, BuiltinType)] = &[
+    (Bool, Int),
+    (Bool, String),
+    (Int, String),
+    (String, Int),
+];
+
+pub fn is_legal_cast(from: DataTypeId, to: DataTypeId, type_arena: &TypeArena) -> bool {
+    if from == to {
+        return true;
+    }
+
+    match (type_arena.get(from), type_arena.get(to)) {
+        (Builtin(from), Builtin(to)) => BUILTIN_CASTS.contains(&(*from, *to)),
+        _ => false,
+    }
+}
@@ -2,25 +2,25 @@ use std::hash::Hash;
 use crate::compiler_context::type_arena::{DataTypeId, TypeArena};
 use crate::operators::binary_operators::BinaryOperator;
 use crate::operators::unary_operators::UnaryOperator;
-use crate::types::data_type::DataType::{Builtin, UserDefined};
+use crate::types::data_type::DataType::{Builtin, List, Map};
 use crate::types::data_type::BuiltinType;
 
 pub trait BuiltinOperatorRegistry {
     type Operands: Hash + Eq;
 
-    fn resolve_builtins(&self, operand: &Self::Operands, type_arena: &TypeArena) -> Option<DataTypeId>;
+    fn resolve_builtins(&self, operand: &Self::Operands, type_arena: &mut TypeArena) -> Option<DataTypeId>;
 }
 
 impl BuiltinOperatorRegistry for UnaryOperator {
     type Operands = DataTypeId;
 
-    fn resolve_builtins(&self, operand_type_id: &DataTypeId, type_arena: &TypeArena) -> Option<DataTypeId> {
+    fn resolve_builtins(&self, operand_type_id: &DataTypeId, type_arena: &mut TypeArena) -> Option<DataTypeId> {
         use UnaryOperator::*;
         use BuiltinType::*;
 
         let operand_type = match type_arena.get(*operand_type_id) {
-            Builtin(builtin_type) => builtin_type,
-            UserDefined(_) => return None,
+            Builtin(builtin_type) => *builtin_type,
+            _ => return None,
         };
 
         let builtin_type = match self {
@@ -47,31 +47,41 @@ impl BuiltinOperatorRegistry for UnaryOperator {
 impl BuiltinOperatorRegistry for BinaryOperator {
     type Operands = (DataTypeId, DataTypeId);
 
-    fn resolve_builtins(&self, operand_ids: &(DataTypeId, DataTypeId), type_arena: &TypeArena) -> Option<DataTypeId> {
+    fn resolve_builtins(&self, operand_ids: &(DataTypeId, DataTypeId), type_arena: &mut TypeArena) -> Option<DataTypeId> {
         use BinaryOperator::*;
         use BuiltinType::*;
 
         let (lhs_type_id, rhs_type_id) = *operand_ids;
 
-        let lhs_type = match type_arena.get(lhs_type_id) {
-            Builtin(builtin_type) => builtin_type,
-            UserDefined(_) => {
-                return match self {
-                    Assign => {
-                        if lhs_type_id == rhs_type_id {
-                            Some(rhs_type_id)
-                        } else {
-                            None
-                        }
-                    },
-                    CommaOperator => Some(rhs_type_id),
-                    _ => None,
-                }
+        if *self == Assign {
+            return if lhs_type_id == rhs_type_id {
+                Some(rhs_type_id)
+            } else {
+                None
+            }
+        }
+
+        if *self == In {
+            let is_member = match type_arena.get(rhs_type_id) {
+                List(element_type) | Map(element_type, _) => *element_type == lhs_type_id,
+                Builtin(String) => lhs_type_id == rhs_type_id,
+                _ => false,
+            };
+
+            return if is_member {
+                Some(type_arena.builtin_type_id(Bool))
+            } else {
+                None
             }
+        }
+
+        let lhs_type = match type_arena.get(lhs_type_id) {
+            Builtin(builtin_type) => *builtin_type,
+            _ => return None,
         };
 
         let rhs_type = match type_arena.get(rhs_type_id) {
-            Builtin(builtin_type) => builtin_type,
+            Builtin(builtin_type) => *builtin_type,
             _ => return None
         };
 
@@ -82,50 +92,49 @@ impl BuiltinOperatorRegistry for BinaryOperator {
                 _ => return None,
             },
 
-            LeftShiftAssign | RightShiftAssign => match (lhs_type, lhs_type) {
+            LeftShiftAssign | RightShiftAssign => match (lhs_type, rhs_type) {
                 (Int, Int) => Int,
                 _ => return None,
             },
 
-            AndAssign | XorAssign | OrAssign => match (lhs_type, lhs_type) {
+            AndAssign | XorAssign | OrAssign => match (lhs_type, rhs_type) {
                 (Int, Int) => Int,
                 _ => return None,
             },
 
-            Add => match (lhs_type, lhs_type) {
+            Add => match (lhs_type, rhs_type) {
                 (Int, Int) => Int,
                 (String, String) => String,
                 _ => return None,
             },
 
-            Sub | Mul | Div | Mod => match (lhs_type, lhs_type) {
+            Sub | Mul | Div | Mod => match (lhs_type, rhs_type) {
                 (Int, Int) => Int,
                 _ => return None,
             },
 
-            BitAnd | BitOr | BitXor | LeftShift | RightShift => match (lhs_type, lhs_type) {
+            BitAnd | BitOr | BitXor | LeftShift | RightShift => match (lhs_type, rhs_type) {
                 (Int, Int) => Int,
                 _ => return None,
             },
 
-            Equal | NotEquals => {
-                if lhs_type == rhs_type {
-                    Bool
-                } else {
-                    return None
-                }
-            },
+            Equal | NotEquals if lhs_type == rhs_type => Bool,
 
-            LessThan | LessOrEqual | GreaterThan | GreaterOrEqual => match (lhs_type, lhs_type) {
+            LessThan | LessOrEqual | GreaterThan | GreaterOrEqual => match (lhs_type, rhs_type) {
                 (Int, Int) | (String, String) => Bool,
                 _ => return None,
             },
 
-            LogicalAnd | LogicalOr => match (lhs_type, lhs_type) {
+            LogicalAnd | LogicalOr => match (lhs_type, rhs_type) {
                 (Bool, Bool) => Bool,
                 _ => return None,
             },
 
+            Range | RangeInclusive => match (lhs_type, rhs_type) {
+                (Int, Int) => return Some(type_arena.range_type_id(lhs_type_id)),
+                _ => return None,
+            },
+
             _ => return None,
         };
 
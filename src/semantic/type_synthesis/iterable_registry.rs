@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use crate::compiler_context::type_arena::{DataTypeId, TypeArena, UserTypeId};
+use crate::types::data_type::BuiltinType;
+use crate::types::data_type::DataType::{Builtin, List, Map, Range, UserDefined};
+
+pub struct IterableRegistry {
+    implementations: HashMap<UserTypeId, DataTypeId>,
+}
+
+impl IterableRegistry {
+    pub fn new() -> Self {
+        Self {
+            implementations: HashMap::new()
+        }
+    }
+
+    pub fn register_iter_method(&mut self, user_type: UserTypeId, item_type: DataTypeId) {
+        self.implementations.insert(user_type, item_type);
+    }
+
+    fn resolve_builtins(iterable_type_id: DataTypeId, type_arena: &mut TypeArena) -> Option<DataTypeId> {
+        use BuiltinType::*;
+
+        let builtin_type = match type_arena.get(iterable_type_id) {
+            Builtin(builtin_type) => *builtin_type,
+            Range(element_type) | List(element_type) => return Some(*element_type),
+            // Maps yield (key, value) pairs
+            &Map(key_type, value_type) => return Some(type_arena.tuple_type_id(vec![key_type, value_type])),
+            _ => return None,
+        };
+
+        let item_type = match builtin_type {
+            // Strings yield their characters as single character strings
+            String => String,
+            _ => return None,
+        };
+
+        Some(type_arena.builtin_type_id(item_type))
+    }
+
+    pub fn resolve_item_type(&self, iterable_type_id: DataTypeId, type_arena: &mut TypeArena) -> Option<DataTypeId> {
+        if let Some(item_type_id) = Self::resolve_builtins(iterable_type_id, type_arena) {
+            return Some(item_type_id);
+        }
+
+        let item_type = match type_arena.get(iterable_type_id) {
+            UserDefined(user_type, _) => *self.implementations.get(user_type)?,
+            _ => return None,
+        };
+        let (_, bindings) = type_arena.user_type_bindings(iterable_type_id)?;
+
+        Some(type_arena.substitute(item_type, &bindings))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::compiler_context::type_arena::{DataTypeId, TraitId, TypeArena};
+use crate::compiler_context::CompilerContext;
+use crate::types::data_type::BuiltinType;
+use crate::types::data_type::BuiltinType::{Bool, Int, Unit};
+use crate::types::data_type::DataType::{Builtin, List, Map, UserDefined};
+use crate::types::function_signature::{FunctionSignature, ParamSignature};
+use std::collections::HashMap;
+use string_interner::DefaultSymbol;
+
+pub struct MethodRegistry;
+
+impl MethodRegistry {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn resolve_list_method(&self, element_type: DataTypeId, method_name: &str, ctx: &CompilerContext) -> Option<FunctionSignature> {
+        let type_arena = &ctx.type_arena;
+
+        Some(match method_name {
+            "len" => FunctionSignature::positional(vec![], type_arena.builtin_type_id(Int)),
+            "push" => FunctionSignature::positional(vec![element_type], type_arena.builtin_type_id(Unit)),
+            "pop" => FunctionSignature::positional(vec![], element_type),
+            _ => return None,
+        })
+    }
+
+    fn resolve_string_method(&self, method_name: &str, ctx: &mut CompilerContext) -> Option<FunctionSignature> {
+        let type_arena = &mut ctx.type_arena;
+        let string = type_arena.builtin_type_id(BuiltinType::String);
+        let (int, bool) = (type_arena.builtin_type_id(Int), type_arena.builtin_type_id(Bool));
+
+        Some(match method_name {
+            "len" => FunctionSignature::positional(vec![], int),
+            "upper" | "lower" | "trim" => FunctionSignature::positional(vec![], string),
+            "contains" | "starts_with" | "ends_with" => FunctionSignature::positional(vec![string], bool),
+            // The index of the first occurrence, or -1 when there is none
+            "find" => FunctionSignature::positional(vec![string], int),
+            "replace" => FunctionSignature::positional(vec![string, string], string),
+            "repeat" => FunctionSignature::positional(vec![int], string),
+            "split" => FunctionSignature::positional(vec![string], type_arena.list_type_id(string)),
+            _ => return None,
+        })
+    }
+
+    fn resolve_map_method(&self, method_name: &str, ctx: &CompilerContext) -> Option<FunctionSignature> {
+        let type_arena = &ctx.type_arena;
+
+        Some(match method_name {
+            "len" => FunctionSignature::positional(vec![], type_arena.builtin_type_id(Int)),
+            _ => return None,
+        })
+    }
+
+    fn resolve_user_method(&self, receiver_type: DataTypeId, method_name: DefaultSymbol, type_arena: &mut TypeArena) -> Option<FunctionSignature> {
+        let (user_type, bindings) = type_arena.user_type_bindings(receiver_type)?;
+        let method = user_type.methods.get(&method_name)?.clone();
+
+        Some(Self::bind_receiver(&method, &bindings, type_arena))
+    }
+
+    pub fn resolve_bound_method(
+        &self,
+        receiver_type: DataTypeId,
+        bounds: &[TraitId],
+        method_name: DefaultSymbol,
+        ctx: &mut CompilerContext,
+    ) -> Option<FunctionSignature> {
+        let method = bounds
+            .iter()
+            .find_map(|&bound| ctx.type_arena.trait_def(bound).method(method_name).cloned())?;
+
+        let bindings = HashMap::from([(ctx.get_symbol("Self"), receiver_type)]);
+        Some(Self::bind_receiver(&method, &bindings, &mut ctx.type_arena))
+    }
+
+    fn bind_receiver(method: &FunctionSignature, bindings: &HashMap<DefaultSymbol, DataTypeId>, type_arena: &mut TypeArena) -> FunctionSignature {
+        let params = method.params[1..]
+            .iter()
+            .map(|param| ParamSignature::new(param.name, type_arena.substitute(param.data_type, bindings), param.has_default))
+            .collect();
+        let return_type = type_arena.substitute(method.return_type, bindings);
+
+        FunctionSignature::new(method.type_params.clone(), params, return_type)
+    }
+
+    pub fn resolve_method(&self, receiver_type: DataTypeId, method_name: DefaultSymbol, ctx: &mut CompilerContext) -> Option<FunctionSignature> {
+        let method_name_str = ctx.get_str(method_name)?;
+
+        match ctx.type_arena.get(receiver_type) {
+            List(element_type) => self.resolve_list_method(*element_type, method_name_str, ctx),
+            Map(_, _) => self.resolve_map_method(method_name_str, ctx),
+            Builtin(BuiltinType::String) => {
+                let method_name_str = method_name_str.to_string();
+                self.resolve_string_method(&method_name_str, ctx)
+            },
+            UserDefined(_, _) => self.resolve_user_method(receiver_type, method_name, &mut ctx.type_arena),
+            _ => None,
+        }
+    }
+}
@@ -1,10 +1,19 @@
 use crate::semantic::type_synthesis::builtin_operator_registry::BuiltinOperatorRegistry;
 use std::collections::HashMap;
 use std::hash::Hash;
+use string_interner::DefaultSymbol;
+use crate::compiler_context::scope::ScopeId;
 use crate::compiler_context::type_arena::{DataTypeId, TypeArena};
 
+#[derive(Debug, Copy, Clone)]
+pub struct OperatorImpl {
+    pub function: DefaultSymbol,
+    pub scope: ScopeId,
+    pub return_type: DataTypeId,
+}
+
 pub struct OperatorRegistry<OpType: Eq + Hash + BuiltinOperatorRegistry> {
-    implementations: HashMap<OpType, HashMap<OpType::Operands, DataTypeId>>
+    implementations: HashMap<OpType, HashMap<OpType::Operands, OperatorImpl>>
 }
 
 impl<OpType: Eq + Hash + BuiltinOperatorRegistry> OperatorRegistry<OpType> {
@@ -15,19 +24,26 @@ impl<OpType: Eq + Hash + BuiltinOperatorRegistry> OperatorRegistry<OpType> {
         }
     }
 
-    pub fn resolve_operation_type(&self, op_type: OpType, operands: &OpType::Operands, type_arena: &TypeArena) -> Option<DataTypeId> {
+    pub fn register(&mut self, op_type: OpType, operands: OpType::Operands, operator_impl: OperatorImpl) -> bool {
+        let definitions = self.implementations.entry(op_type).or_default();
+
+        if definitions.contains_key(&operands) {
+            return false;
+        }
+
+        definitions.insert(operands, operator_impl);
+        true
+    }
+
+    pub fn resolve_overload(&self, op_type: OpType, operands: &OpType::Operands) -> Option<OperatorImpl> {
+        self.implementations.get(&op_type)?.get(operands).copied()
+    }
+
+    pub fn resolve_operation_type(&self, op_type: OpType, operands: &OpType::Operands, type_arena: &mut TypeArena) -> Option<DataTypeId> {
         if let Some(data_type_id) = op_type.resolve_builtins(operands, type_arena) {
             return Some(data_type_id);
         }
         
-        let definitions = match self.implementations.get(&op_type) {
-            Some(definitions) => definitions,
-            None => return None,
-        };
-
-        match definitions.get(operands) {
-            Some(data_type_id) => Some(*data_type_id),
-            None => None,
-        }
+        self.resolve_overload(op_type, operands).map(|operator_impl| operator_impl.return_type)
     }
 }
@@ -1,20 +1,81 @@
+use crate::ast::access_node::{AccessNode, Member};
+use crate::ast::argument::Argument;
 use crate::ast::arena_ast::{ASTNodeId, AST};
-use crate::ast::ast_node::ASTNodeType;
-use crate::compiler_context::type_arena::DataTypeId;
+use crate::ast::ast_node::{ASTNodeType, SpannableASTNode};
+use crate::ast::binary_operator_node::BinaryOperatorNode;
+use crate::ast::cast_node::CastNode;
+use crate::ast::declaration_node::DeclarationKind;
+use crate::ast::enum_def_node::VariantDef;
+use crate::ast::function_call_node::FunctionCallNode;
+use crate::ast::function_def_node::Parameter;
+use crate::ast::lambda_node::LambdaParam;
+use crate::ast::import_node::ImportedName;
+use crate::ast::if_node::ConditionBlock;
+use crate::ast::map_node::MapEntry;
+use crate::ast::struct_def_node::FieldDef;
+use crate::ast::variable_node::VariableNode;
+use crate::compiler_context::module::ModuleId;
+use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::symbol::{Mutability, NamedType, Symbol, SymbolId};
+use crate::compiler_context::type_arena::{DataTypeId, TraitId, UserTypeId};
 use crate::compiler_context::CompilerContext;
+use crate::error::spanned_error::SpannableError;
 use crate::operators::binary_operators::BinaryOperator;
 use crate::operators::binary_operators::BinaryOperator::Assign;
+use crate::operators::overloaded_operator::OverloadedOperator;
 use crate::operators::unary_operators::UnaryOperator;
+use crate::semantic::const_evaluation::ConstEvaluator;
+use crate::semantic::error::SemanticError;
 use crate::semantic::error::SemanticError::*;
 use crate::semantic::error::SemanticResult;
-use crate::semantic::type_synthesis::operator_registry::OperatorRegistry;
-use crate::types::data_type::BuiltinType::{Int, String};
+use crate::semantic::type_resolution::resolve_type_annotation;
+use crate::semantic::type_synthesis::builtin_casts::is_legal_cast;
+use crate::semantic::type_synthesis::iterable_registry::IterableRegistry;
+use crate::semantic::type_synthesis::method_registry::MethodRegistry;
+use crate::semantic::type_synthesis::operator_registry::OperatorImpl;
+use crate::source::source_span::SourceSpan;
+use crate::types::data_type::BuiltinType::{Bool, Int, String, Unit};
+use crate::types::data_type::DataType;
+use crate::types::data_type::DataType::Builtin;
+use crate::types::function_signature::{FunctionSignature, ParamSignature};
+use crate::types::type_annotation::TypeAnnotation;
+use crate::types::trait_def::TraitDef;
+use crate::types::type_param::TypeParam;
+use crate::types::user_type::{UserTypeDef, UserTypeKind};
+use std::collections::HashMap;
 use string_interner::DefaultSymbol;
 
+enum Bound {
+    Trait(TraitId),
+    Formattable,
+}
+
+struct BoundObligation {
+    data_type: DataTypeId,
+    bound: Bound,
+    type_params_in_scope: Vec<TypeParam>,
+    span: SourceSpan,
+}
+
+struct HashObligation {
+    key_type: DataTypeId,
+    span: SourceSpan,
+}
+
+struct CaptureFrame {
+    body_scope: ScopeId,
+    captures: Vec<SymbolId>,
+}
+
 pub struct TypeSynthesizer<'a> {
     ast: AST,
-    unary_op_impl: OperatorRegistry<UnaryOperator>,
-    binary_op_impl: OperatorRegistry<BinaryOperator>,
+    iterable_impl: IterableRegistry,
+    method_impl: MethodRegistry,
+    return_types: Vec<DataTypeId>,
+    type_params: Vec<TypeParam>,
+    capture_frames: Vec<CaptureFrame>,
+    bound_obligations: Vec<BoundObligation>,
+    hash_obligations: Vec<HashObligation>,
     ctx: &'a mut CompilerContext,
 }
 
@@ -22,33 +83,417 @@ impl<'a> TypeSynthesizer<'a> {
     fn new(ast: AST, ctx: &'a mut CompilerContext) -> Self {
         Self {
             ast,
-            unary_op_impl: OperatorRegistry::new(),
-            binary_op_impl: OperatorRegistry::new(),
+            iterable_impl: IterableRegistry::new(),
+            method_impl: MethodRegistry::new(),
+            return_types: Vec::new(),
+            type_params: Vec::new(),
+            capture_frames: Vec::new(),
+            bound_obligations: Vec::new(),
+            hash_obligations: Vec::new(),
             ctx,
         }
     }
     
-    fn compute_variable_type(&self, var_name: DefaultSymbol) -> SemanticResult<Option<DataTypeId>> {
-        match self.ctx.symbol_table.lookup(var_name) {
-            None => Ok(None),
-            Some(symbol) => Ok(Some(symbol.data_type))
+    fn record_capture(&mut self, var_name: DefaultSymbol, scope: ScopeId) {
+        let symbol_table = &self.ctx.symbol_table;
+
+        let symbol_id = match symbol_table.resolve(var_name, scope) {
+            Some(symbol_id) if !symbol_table.is_top_level(symbol_id.scope) => symbol_id,
+            _ => return,
+        };
+
+        for frame in &mut self.capture_frames {
+            if !symbol_table.is_within(symbol_id.scope, frame.body_scope) && !frame.captures.contains(&symbol_id) {
+                frame.captures.push(symbol_id);
+            }
+        }
+    }
+
+    fn compute_variable_type(&mut self, var_name: DefaultSymbol, scope: ScopeId, span: SourceSpan) -> SemanticResult<Option<DataTypeId>> {
+        self.record_capture(var_name, scope);
+
+        let (data_type, type_params) = match self.ctx.symbol_table.lookup(var_name, scope) {
+            None => return Ok(None),
+            Some(symbol) => (symbol.data_type, symbol.type_params.clone()),
+        };
+
+        if type_params.is_empty() {
+            return Ok(Some(data_type));
+        }
+
+        let bindings = self.fresh_type_args(&type_params, span);
+        let data_type = self.ctx.type_arena.substitute(data_type, &bindings);
+        self.require_hashable_keys(data_type, span)?;
+
+        Ok(Some(data_type))
+    }
+
+    fn require_hashable_keys(&mut self, data_type: DataTypeId, span: SourceSpan) -> SemanticResult<()> {
+        for key_type in self.ctx.type_arena.map_key_types(data_type) {
+            self.require_hashable(key_type, span)?;
+        }
+
+        Ok(())
+    }
+
+    fn require_hashable(&mut self, key_type: DataTypeId, span: SourceSpan) -> SemanticResult<()> {
+        let resolved_key_type = self.ctx.type_arena.resolve(key_type);
+
+        match self.ctx.type_arena.get(resolved_key_type) {
+            DataType::TypeVar(_) => {
+                self.hash_obligations.push(HashObligation { key_type, span });
+                Ok(())
+            },
+            data_type if data_type.is_hashable() => Ok(()),
+            _ => {
+                let mut error = UnhashableKey(self.display_type(Some(resolved_key_type))).at(span);
+
+                if let Some(inferred_at) = self.ctx.type_arena.binding_site(key_type) && inferred_at != span {
+                    error = error.with_note("Note: type was inferred from here".to_string(), inferred_at);
+                }
+
+                Err(error)
+            },
+        }
+    }
+
+    fn declare_annotated_variable(&mut self, var_name: DefaultSymbol, annotation: TypeAnnotation, var_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        let node = self.ast.lookup(var_node);
+        let (span, scope) = (node.span, node.scope);
+
+        let data_type = self.resolve_annotation(&annotation, scope)?;
+        self.ctx.symbol_table.add_symbol(scope, Symbol::new(var_name, data_type, span));
+
+        Ok(data_type)
+    }
+
+    fn fresh_type_args(&mut self, type_params: &[TypeParam], span: SourceSpan) -> HashMap<DefaultSymbol, DataTypeId> {
+        let mut bindings = HashMap::new();
+
+        for type_param in type_params {
+            let type_arg = self.ctx.type_arena.fresh_type_var();
+
+            let bounds = type_param.traits
+                .iter()
+                .map(|&trait_id| Bound::Trait(trait_id))
+                .chain(type_param.formattable.then_some(Bound::Formattable));
+
+            for bound in bounds {
+                self.bound_obligations.push(BoundObligation {
+                    data_type: type_arg,
+                    bound,
+                    type_params_in_scope: self.type_params.clone(),
+                    span,
+                });
+            }
+
+            bindings.insert(type_param.name, type_arg);
+        }
+
+        bindings
+    }
+
+    fn implements_trait(&self, data_type: DataTypeId, trait_id: TraitId, type_params_in_scope: &[TypeParam]) -> bool {
+        match self.ctx.type_arena.get(data_type) {
+            DataType::UserDefined(user_type, type_args) => {
+                let impl_type_params = match self.ctx.type_arena.user_type(*user_type).traits.get(&trait_id) {
+                    Some(impl_type_params) => impl_type_params,
+                    None => return false,
+                };
+
+                impl_type_params.iter().zip(type_args).all(|(type_param, &type_arg)| {
+                    type_param.traits.iter().all(|&bound| self.implements_trait(type_arg, bound, type_params_in_scope))
+                })
+            },
+            DataType::TypeParam(name) => type_params_in_scope
+                .iter()
+                .rev()
+                .find(|type_param| type_param.name == *name)
+                .is_some_and(|type_param| type_param.traits.contains(&trait_id)),
+            _ => false,
+        }
+    }
+
+    /// Checks the bounds of the generic instantiations whose type arguments have been inferred.
+    /// The remaining ones are kept for later unless `all_constraints_known` is set.
+    fn check_bound_obligations(&mut self, all_constraints_known: bool) -> SemanticResult<()> {
+        let obligations = std::mem::take(&mut self.bound_obligations);
+
+        for obligation in obligations {
+            let data_type = self.ctx.type_arena.resolve(obligation.data_type);
+
+            if self.ctx.type_arena.contains_unsolved(data_type) {
+                if !all_constraints_known {
+                    self.bound_obligations.push(obligation);
+                }
+                continue;
+            }
+
+            match obligation.bound {
+                Bound::Trait(trait_id) if !self.implements_trait(data_type, trait_id, &obligation.type_params_in_scope) => {
+                    let trait_name = self.ctx.type_arena.trait_def(trait_id).name;
+
+                    return Err(UnsatisfiedBound {
+                        type_name: self.display_type(Some(data_type)),
+                        trait_name: self.ctx.get_str(trait_name).unwrap_or_default().to_string(),
+                    }.at(obligation.span));
+                },
+                Bound::Formattable if !self.is_formattable(data_type) => {
+                    return Err(self.not_formattable(data_type).at(obligation.span));
+                },
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+
+    fn instantiate_signature(&mut self, signature: &FunctionSignature, span: SourceSpan) -> SemanticResult<FunctionSignature> {
+        if signature.type_params.is_empty() {
+            return Ok(signature.clone());
+        }
+
+        let bindings = self.fresh_type_args(&signature.type_params, span);
+        let signature = self.substitute_signature(signature, &bindings);
+
+        let function_type = self.signature_type_id(&signature);
+        self.require_hashable_keys(function_type, span)?;
+
+        Ok(FunctionSignature {
+            type_params: Vec::new(),
+            ..signature
+        })
+    }
+
+    fn substitute_signature(&mut self, signature: &FunctionSignature, bindings: &HashMap<DefaultSymbol, DataTypeId>) -> FunctionSignature {
+        let params = signature.params
+            .iter()
+            .map(|param| ParamSignature::new(param.name, self.ctx.type_arena.substitute(param.data_type, bindings), param.has_default))
+            .collect();
+        let return_type = self.ctx.type_arena.substitute(signature.return_type, bindings);
+
+        FunctionSignature::new(signature.type_params.clone(), params, return_type)
+    }
+
+    fn expect_type(
+        &mut self,
+        expected: DataTypeId,
+        found: Option<DataTypeId>,
+        span: SourceSpan,
+        mismatch: impl FnOnce(std::string::String, std::string::String) -> SemanticError,
+    ) -> SemanticResult<()> {
+        if let Some(found) = found && self.ctx.type_arena.unify(expected, found, span) {
+            return Ok(());
+        }
+
+        let mut error = mismatch(self.display_type(Some(expected)), self.display_type(found)).at(span);
+
+        let type_arena = &self.ctx.type_arena;
+        let inferred_at = type_arena.binding_site(expected).or_else(|| found.and_then(|found| type_arena.binding_site(found)));
+
+        if let Some(inferred_at) = inferred_at && inferred_at != span {
+            error = error.with_note("Note: type was inferred from here".to_string(), inferred_at);
         }
+
+        Err(error)
+    }
+
+    fn resolved_type(&mut self, node: ASTNodeId) -> Option<DataTypeId> {
+        let data_type = self.ast.lookup(node).data_type?;
+        Some(self.ctx.type_arena.resolve(data_type))
     }
 
-    fn compute_unary_operation_type(&self, operator_type: UnaryOperator, operand: ASTNodeId) -> SemanticResult<DataTypeId> {
+    fn compute_unary_operation_type(&mut self, operator_type: UnaryOperator, operand: ASTNodeId, op_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(operand)?;
 
-        let operand_type = match &self.ast.lookup(operand).data_type {
+        let span = self.ast.lookup(op_node).span;
+
+        if operator_type.is_increment() {
+            self.check_target_mutable(operand)?;
+        }
+
+        let operand_type = match self.resolved_type(operand) {
             Some(e) => e,
-            None => return Err(MismatchedUnaryOperatorTypes(operator_type)),
+            None => return Err(MismatchedUnaryOperatorTypes(operator_type).at(span)),
         };
 
-        match self.unary_op_impl.resolve_operation_type(operator_type, operand_type, &self.ctx.type_arena) {
+        if let Some(operator_impl) = self.ctx.unary_op_impl.resolve_overload(operator_type, &operand_type) {
+            self.lower_to_operator_call(op_node, operator_impl, vec![(operand, operand_type)]);
+        }
+
+        match self.ctx.unary_op_impl.resolve_operation_type(operator_type, &operand_type, &mut self.ctx.type_arena) {
             Some(data_type) => Ok(data_type),
-            None => Err(MismatchedUnaryOperatorTypes(operator_type)),
+            None => Err(MismatchedUnaryOperatorTypes(operator_type).at(span)),
+        }
+    }
+
+    fn lower_to_operator_call(&mut self, op_node: ASTNodeId, operator_impl: OperatorImpl, operands: Vec<(ASTNodeId, DataTypeId)>) {
+        let span = self.ast.lookup(op_node).span;
+
+        let operand_types = operands.iter().map(|&(_, operand_type)| operand_type).collect();
+        let function_type = self.ctx.type_arena.function_type_id(operand_types, operator_impl.return_type);
+
+        let function = self.ast.add_node(VariableNode::new(operator_impl.function, None).at(span, operator_impl.scope));
+        self.ast.lookup_mut(function).data_type = Some(function_type);
+
+        let args = operands
+            .into_iter()
+            .map(|(operand, _)| Argument::new(None, operand, self.ast.lookup(operand).span))
+            .collect();
+
+        self.ast.lookup_mut(op_node).node_data_type = ASTNodeType::FunctionCall(FunctionCallNode::new(function, args));
+    }
+
+    fn lower_compound_assignment(
+        &mut self,
+        operation: BinaryOperator,
+        operator_impl: OperatorImpl,
+        (target, target_type): (ASTNodeId, DataTypeId),
+        (value, value_type): (ASTNodeId, DataTypeId),
+        op_node: ASTNodeId,
+    ) -> SemanticResult<DataTypeId> {
+        let node = self.ast.lookup(op_node);
+        let (span, scope) = (node.span, node.scope);
+
+        self.expect_type(target_type, Some(operator_impl.return_type), span, |expected, found| {
+            MismatchedAssignment { expected, found }
+        })?;
+
+        let result = self.ast.add_node(BinaryOperatorNode::new(operation, target, value).at(span, scope));
+        self.ast.lookup_mut(result).data_type = Some(operator_impl.return_type);
+        self.lower_to_operator_call(result, operator_impl, vec![(target, target_type), (value, value_type)]);
+
+        self.ast.lookup_mut(op_node).node_data_type = ASTNodeType::BinaryOperator(BinaryOperatorNode::new(Assign, target, result));
+        Ok(target_type)
+    }
+
+    fn bind_assignment_target(&mut self, target: ASTNodeId, data_type: DataTypeId, mutability: &Mutability) -> SemanticResult<()> {
+        let target_node = self.ast.lookup(target);
+        let (span, scope) = (target_node.span, target_node.scope);
+
+        if let Some(target_type) = target_node.data_type {
+            return self.expect_type(target_type, Some(data_type), span, |expected, found| {
+                MismatchedAssignment { expected, found }
+            });
+        }
+
+        match &target_node.node_data_type {
+            ASTNodeType::Variable(var) => {
+                let name = var.name;
+
+                let data_type = match var.type_annotation.clone() {
+                    Some(annotation) => {
+                        let annotated_type = self.resolve_annotation(&annotation, scope)?;
+                        self.expect_type(annotated_type, Some(data_type), span, |expected, found| {
+                            MismatchedAssignment { expected, found }
+                        })?;
+                        annotated_type
+                    },
+                    None => data_type,
+                };
+
+                let declares_function = self.ctx.symbol_table
+                    .resolve(name, scope)
+                    .filter(|symbol_id| symbol_id.scope == scope)
+                    .and_then(|_| self.ctx.symbol_table.lookup(name, scope))
+                    .is_some_and(|symbol| symbol.signature.is_some());
+
+                if declares_function {
+                    return Err(DuplicateDefinition(self.ctx.get_str(name).unwrap_or_default().to_string()).at(span));
+                }
+
+                let symbol = Symbol::new(name, data_type, span).with_mutability(mutability.clone());
+                self.ctx.symbol_table.add_symbol(scope, symbol);
+            },
+
+            ASTNodeType::Tuple(tuple) => {
+                let elements = tuple.elements.clone();
+
+                if self.ctx.type_arena.is_unsolved(data_type) {
+                    let fresh_types = elements.iter().map(|_| self.ctx.type_arena.fresh_type_var()).collect();
+                    let tuple_type = self.ctx.type_arena.tuple_type_id(fresh_types);
+                    self.ctx.type_arena.unify(data_type, tuple_type, span);
+                }
+
+                let element_types = match self.ctx.type_arena.get(self.ctx.type_arena.find(data_type)) {
+                    DataType::Tuple(element_types) if element_types.len() == elements.len() => element_types.clone(),
+                    _ => return Err(MismatchedDestructure {
+                        expected: elements.len(),
+                        found: self.display_type(Some(data_type)),
+                    }.at(span)),
+                };
+
+                for (element, element_type) in elements.into_iter().zip(element_types) {
+                    self.bind_assignment_target(element, element_type, mutability)?;
+                }
+            },
+
+            _ => {},
+        }
+
+        self.ast.lookup_mut(target).data_type = Some(data_type);
+
+        Ok(())
+    }
+
+    fn check_target_mutable(&self, target: ASTNodeId) -> SemanticResult<()> {
+        let target_node = self.ast.lookup(target);
+
+        match &target_node.node_data_type {
+            ASTNodeType::Variable(var) => {
+                let symbol = match self.ctx.symbol_table.lookup(var.name, target_node.scope) {
+                    Some(symbol) => symbol,
+                    None => return Ok(()),
+                };
+
+                let name = self.ctx.get_str(var.name).unwrap_or_default().to_string();
+
+                let error = match symbol.mutability {
+                    Mutability::Mutable => return Ok(()),
+                    Mutability::Immutable => AssignToImmutable(name),
+                    Mutability::Const(_) => AssignToConst(name),
+                };
+
+                let error = error.at(target_node.span);
+
+                // Prelude functions are declared in the global scope and have no definition to point to
+                match self.ctx.symbol_table.resolve(var.name, target_node.scope) {
+                    Some(symbol_id) if symbol_id.scope == self.ctx.symbol_table.global_scope() => Err(error),
+                    _ => Err(error.with_note("Note: declared here".to_string(), symbol.def_span())),
+                }
+            },
+
+            ASTNodeType::Tuple(tuple) => tuple.elements
+                .iter()
+                .try_for_each(|&element| self.check_target_mutable(element)),
+
+            _ => Ok(()),
         }
     }
 
-    fn compute_binary_operation_type(&mut self, operator_type: BinaryOperator, left: ASTNodeId, right: ASTNodeId) -> SemanticResult<DataTypeId> {
+    fn compute_declaration_type(&mut self, kind: DeclarationKind, target: ASTNodeId, value: ASTNodeId, declaration_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(value)?;
+
+        let value_type = match self.ast.lookup(value).data_type {
+            Some(value_type) => value_type,
+            None => return Err(MismatchedBinaryOperatorTypes(Assign).at(self.ast.lookup(declaration_node).span)),
+        };
+
+        let mutability = match kind {
+            DeclarationKind::Let => Mutability::Immutable,
+            DeclarationKind::Const => Mutability::Const(ConstEvaluator::new(&self.ast, self.ctx).evaluate(value)?),
+        };
+
+        self.bind_assignment_target(target, value_type, &mutability)?;
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn compute_binary_operation_type(&mut self, operator_type: BinaryOperator, left: ASTNodeId, right: ASTNodeId, op_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(left)?;
+        self.compute_type(right)?;
+
+        let span = self.ast.lookup(op_node).span;
 
         let rhs_type_opt = self.ast.lookup(right).data_type;
         let lhs_type_opt = self.ast.lookup(left).data_type;
@@ -56,7 +501,7 @@ impl<'a> TypeSynthesizer<'a> {
         let rhs_type = match rhs_type_opt {
             Some(data_type) => data_type,
             None => {
-                return Err(MismatchedBinaryOperatorTypes(operator_type))
+                return Err(MismatchedBinaryOperatorTypes(operator_type).at(span))
             },
         };
 
@@ -64,58 +509,1612 @@ impl<'a> TypeSynthesizer<'a> {
             Some(data_type) => data_type,
             None => {
                 return if operator_type == Assign {
-                    self.ast.lookup_mut(left).data_type = Some(rhs_type.clone());
+                    self.bind_assignment_target(left, rhs_type, &Mutability::Mutable)?;
                     Ok(rhs_type)
                 } else {
-                    Err(MismatchedBinaryOperatorTypes(operator_type))
+                    Err(MismatchedBinaryOperatorTypes(operator_type).at(span))
                 }
             }
         };
 
-        match self.binary_op_impl.resolve_operation_type(operator_type, &(lhs_type, rhs_type), &self.ctx.type_arena) {
+        if operator_type.is_assignment() {
+            self.check_target_mutable(left)?;
+        }
+
+        if operator_type == Assign {
+            let rhs_span = self.ast.lookup(right).span;
+            self.expect_type(lhs_type, Some(rhs_type), rhs_span, |expected, found| {
+                MismatchedAssignment { expected, found }
+            })?;
+            return Ok(lhs_type);
+        }
+
+        let type_arena = &mut self.ctx.type_arena;
+
+        if operator_type == BinaryOperator::In {
+            match type_arena.get(type_arena.find(rhs_type)).clone() {
+                DataType::List(element_type) | DataType::Map(element_type, _) => {
+                    type_arena.unify(element_type, lhs_type, span);
+                },
+                _ => {},
+            }
+        } else if type_arena.is_unsolved(lhs_type) || type_arena.is_unsolved(rhs_type) {
+            // An operand whose type is still unknown takes the type of the other one
+            type_arena.unify(lhs_type, rhs_type, span);
+        }
+
+        let operands = (type_arena.resolve(lhs_type), type_arena.resolve(rhs_type));
+
+        if let Some(operation) = operator_type.compound_operation()
+            && let Some(operator_impl) = self.ctx.binary_op_impl.resolve_overload(operation, &operands) {
+            return self.lower_compound_assignment(operation, operator_impl, (left, operands.0), (right, operands.1), op_node);
+        }
+
+        if let Some(operator_impl) = self.ctx.binary_op_impl.resolve_overload(operator_type, &operands) {
+            self.lower_to_operator_call(op_node, operator_impl, vec![(left, operands.0), (right, operands.1)]);
+        }
+
+        match self.ctx.binary_op_impl.resolve_operation_type(operator_type, &operands, &mut self.ctx.type_arena) {
             Some(data_type) => Ok(data_type),
-            None => Err(MismatchedBinaryOperatorTypes(operator_type)),
+            None => Err(MismatchedBinaryOperatorTypes(operator_type).at(span)),
         }
     }
 
-    fn compute_type(&mut self, ast_node_id: ASTNodeId) -> SemanticResult<()> {
-        use ASTNodeType::*;
+    fn display_type(&self, data_type: Option<DataTypeId>) -> std::string::String {
+        match data_type {
+            Some(data_type) => self.ctx.type_arena.get(data_type).display(self.ctx),
+            None => "an unresolved type".to_string(),
+        }
+    }
 
-        let node = self.ast.lookup(ast_node_id);
+    fn compute_types(&mut self, nodes: &[ASTNodeId]) -> SemanticResult<()> {
+        for &node_id in nodes {
+            self.compute_type(node_id)?;
+        }
 
-        if let Some(_) = &node.data_type {
-            return Ok(());
+        Ok(())
+    }
+
+    fn resolve_annotation(&mut self, annotation: &TypeAnnotation, scope: ScopeId) -> SemanticResult<DataTypeId> {
+        resolve_type_annotation(annotation, &self.type_params, scope, self.ctx)
+    }
+
+    fn lookup_user_type(&self, name: DefaultSymbol, scope: ScopeId) -> Option<UserTypeId> {
+        match self.ctx.symbol_table.lookup_type(name, scope) {
+            Some(NamedType::UserType(user_type)) => Some(user_type),
+            _ => None,
         }
+    }
 
-        let data_type = match &node.node_data_type {
-            IntLiteral(_) => Some(self.ctx.type_arena.builtin_type_id(Int)),
-            StringLiteral(_) => Some(self.ctx.type_arena.builtin_type_id(String)),
+    fn lookup_trait(&self, name: DefaultSymbol, scope: ScopeId) -> Option<TraitId> {
+        match self.ctx.symbol_table.lookup_type(name, scope) {
+            Some(NamedType::Trait(trait_id)) => Some(trait_id),
+            _ => None,
+        }
+    }
 
-            Variable(var) => self.compute_variable_type(var.name)?,
+    fn resolve_type_params(&mut self, type_params: &[TypeParam], scope: ScopeId) -> SemanticResult<Vec<TypeParam>> {
+        type_params
+            .iter()
+            .map(|type_param| {
+                let traits = type_param.bounds
+                    .iter()
+                    .map(|&bound| match self.lookup_trait(bound, scope) {
+                        Some(trait_id) => Ok(trait_id),
+                        None => Err(UnknownTrait(self.ctx.get_str(bound).unwrap_or_default().to_string()).at(type_param.span)),
+                    })
+                    .collect::<SemanticResult<Vec<_>>>()?;
 
-            UnaryOperator(op) => {
-                Some(self.compute_unary_operation_type(op.op_type, op.operand)?)
+                Ok(TypeParam {
+                    traits,
+                    ..type_param.clone()
+                })
+            })
+            .collect()
+    }
+
+    fn with_type_params<T>(
+        &mut self,
+        type_params: &[TypeParam],
+        check: impl FnOnce(&mut Self) -> SemanticResult<T>,
+    ) -> SemanticResult<T> {
+        let outer_type_param_count = self.type_params.len();
+
+        self.type_params.extend(type_params.iter().cloned());
+        let result = check(self);
+        self.type_params.truncate(outer_type_param_count);
+
+        result
+    }
+
+    fn declare_function(&mut self, func_def_node_id: ASTNodeId) -> SemanticResult<()> {
+        let node = self.ast.lookup(func_def_node_id);
+
+        let func_def = match &node.node_data_type {
+            ASTNodeType::FunctionDef(func_def) => func_def,
+            _ => return Ok(()),
+        };
+
+        let (name, span, scope) = (func_def.name, node.span, node.scope);
+        let type_params = func_def.type_params.clone();
+        let params = func_def.params.clone();
+        let return_annotation = func_def.return_type.clone();
+
+        let operator = func_def.operator;
+
+        let type_params = self.resolve_type_params(&type_params, scope)?;
+
+        let signature = self.with_type_params(&type_params.clone(), |synthesizer| {
+            synthesizer.resolve_function_signature(type_params, params, return_annotation, scope)
+        })?;
+
+        let name = match operator {
+            Some(operator) => {
+                let function_name = self.register_operator(operator, &signature, span, scope)?;
+
+                if let ASTNodeType::FunctionDef(func_def) = &mut self.ast.lookup_mut(func_def_node_id).node_data_type {
+                    func_def.name = function_name;
+                }
+
+                function_name
             },
+            None => name,
+        };
 
-            BinaryOperator(op) => {
-                Some(self.compute_binary_operation_type(op.op_type, op.left, op.right)?)
+        let function_type = self.ctx.type_arena.function_type_id(signature.param_types(), signature.return_type);
+        self.ctx.symbol_table.add_symbol(scope, Symbol::function(name, function_type, signature, span));
+
+        Ok(())
+    }
+
+    fn register_operator(
+        &mut self,
+        operator: OverloadedOperator,
+        signature: &FunctionSignature,
+        span: SourceSpan,
+        scope: ScopeId,
+    ) -> SemanticResult<DefaultSymbol> {
+        if !signature.type_params.is_empty() {
+            return Err(GenericOperatorOverload(operator.to_string()).at(span));
+        }
+
+        let operand_types = signature.param_types();
+
+        if !operand_types.iter().any(|&operand_type| matches!(self.ctx.type_arena.get(operand_type), DataType::UserDefined(..))) {
+            return Err(BuiltinOperatorOverload(operator.to_string()).at(span));
+        }
+
+        let operand_names = operand_types
+            .iter()
+            .map(|&operand_type| self.display_type(Some(operand_type)))
+            .collect::<Vec<_>>();
+        let function_name = format!("{}({})", operator, operand_names.join(", "));
+
+        let operator_impl = OperatorImpl {
+            function: self.ctx.get_symbol(&function_name),
+            scope,
+            return_type: signature.return_type,
+        };
+
+        let registered = match operator {
+            OverloadedOperator::Unary(op_type) => self.ctx.unary_op_impl.register(op_type, operand_types[0], operator_impl),
+            OverloadedOperator::Binary(op_type) => {
+                self.ctx.binary_op_impl.register(op_type, (operand_types[0], operand_types[1]), operator_impl)
             },
+        };
+
+        if !registered {
+            return Err(DuplicateOperatorOverload(function_name).at(span));
+        }
+
+        Ok(operator_impl.function)
+    }
+
+    fn resolve_function_signature(
+        &mut self,
+        type_params: Vec<TypeParam>,
+        params: Vec<Parameter>,
+        return_annotation: Option<TypeAnnotation>,
+        scope: ScopeId,
+    ) -> SemanticResult<FunctionSignature> {
+        let mut param_signatures = Vec::new();
+
+        for param in params {
+            let param_type = self.resolve_annotation(&param.type_annotation, scope)?;
+
+            if let Some(default_value) = param.default_value {
+                self.compute_type(default_value)?;
+                let default_node = self.ast.lookup(default_value);
+
+                self.expect_type(param_type, default_node.data_type, default_node.span, |expected, found| {
+                    MismatchedDefaultValue { expected, found }
+                })?;
+            }
 
-            _ => unimplemented!("{:?} type resolution unimplemented", node.node_data_type),
+            param_signatures.push(ParamSignature::new(Some(param.name), param_type, param.default_value.is_some()));
+        }
+
+        let return_type = match &return_annotation {
+            Some(return_type) => self.resolve_annotation(return_type, scope)?,
+            None => self.ctx.type_arena.builtin_type_id(Unit),
         };
 
-        self.ast.lookup_mut(ast_node_id).data_type = data_type;
+        Ok(FunctionSignature::new(type_params, param_signatures, return_type))
+    }
+
+    fn declare_user_type(&mut self, node_id: ASTNodeId) -> SemanticResult<()> {
+        let node = self.ast.lookup(node_id);
+
+        let name = match &node.node_data_type {
+            ASTNodeType::StructDef(struct_def) => struct_def.name,
+            ASTNodeType::EnumDef(enum_def) => enum_def.name,
+            ASTNodeType::TraitDef(trait_def) => trait_def.name,
+            _ => return Ok(()),
+        };
+
+        let scope = node.scope;
 
+        if self.ctx.symbol_table.declares_type(scope, name) {
+            return Err(DuplicateType(self.ctx.get_str(name).unwrap_or_default().to_string()).at(node.span));
+        }
+
+        let (type_params, kind) = match &node.node_data_type {
+            ASTNodeType::StructDef(struct_def) => {
+                (struct_def.type_params.clone(), UserTypeKind::Struct { fields: Vec::new() })
+            },
+            ASTNodeType::EnumDef(enum_def) => {
+                (enum_def.type_params.clone(), UserTypeKind::Enum { variants: Vec::new() })
+            },
+            _ => {
+                let trait_id = self.ctx.type_arena.define_trait(TraitDef::new(name));
+                self.ctx.symbol_table.add_type(scope, name, NamedType::Trait(trait_id));
+                return Ok(());
+            },
+        };
+
+        let user_type = self.ctx.type_arena.define_user_type(UserTypeDef::new(name, type_params, kind));
+        self.ctx.symbol_table.add_type(scope, name, NamedType::UserType(user_type));
         Ok(())
     }
 
-    pub fn compute_ast_types(ast: AST, ctx: &mut CompilerContext) -> SemanticResult<AST> {
-        let mut synthesizer = TypeSynthesizer::new(ast, ctx);
+    fn self_type_id(&mut self, user_type: UserTypeId, type_params: &[TypeParam]) -> DataTypeId {
+        let type_args = type_params
+            .iter()
+            .map(|type_param| self.ctx.type_arena.type_param_id(type_param.name))
+            .collect();
+
+        self.ctx.type_arena.user_type_id(user_type, type_args)
+    }
+
+    fn resolve_method_signature(&mut self, method_node_id: ASTNodeId) -> SemanticResult<(DefaultSymbol, FunctionSignature)> {
+        let node = self.ast.lookup(method_node_id);
 
-        for id in synthesizer.ast.ast_node_id_iter() {
-            synthesizer.compute_type(id)?;
+        let method = match &node.node_data_type {
+            ASTNodeType::FunctionDef(method) => method,
+            _ => unreachable!("Struct methods must be function definitions"),
+        };
+
+        let (name, span, scope) = (method.name, node.span, node.scope);
+
+        if let Some(operator) = method.operator {
+            return Err(OperatorMethod(operator.to_string()).at(span));
         }
 
-        Ok(synthesizer.ast)
+        let type_params = method.type_params.clone();
+        let params = method.params.clone();
+        let return_annotation = method.return_type.clone();
+
+        let self_symbol = self.ctx.get_symbol("self");
+
+        if params.first().is_none_or(|param| param.name != self_symbol) {
+            return Err(MissingSelfParameter(self.ctx.get_str(name).unwrap_or_default().to_string()).at(span));
+        }
+
+        let type_params = self.resolve_type_params(&type_params, scope)?;
+
+        let signature = self.with_type_params(&type_params.clone(), |synthesizer| {
+            synthesizer.resolve_function_signature(type_params, params, return_annotation, scope)
+        })?;
+
+        Ok((name, signature))
+    }
+
+    fn define_struct(
+        &mut self,
+        name: DefaultSymbol,
+        type_params: Vec<TypeParam>,
+        fields: Vec<FieldDef>,
+        methods: Vec<ASTNodeId>,
+        struct_node: ASTNodeId,
+    ) -> SemanticResult<()> {
+        let node = self.ast.lookup(struct_node);
+        let (span, scope) = (node.span, node.scope);
+
+        let user_type = self.lookup_user_type(name, scope).expect("User type must be declared before it is defined");
+        let type_params = self.resolve_type_params(&type_params, scope)?;
+
+        let (field_types, method_signatures) = self.with_type_params(&type_params, |synthesizer| {
+            let field_types = fields
+                .iter()
+                .map(|field| Ok((field.name, synthesizer.resolve_annotation(&field.type_annotation, scope)?)))
+                .collect::<SemanticResult<Vec<_>>>()?;
+
+            let method_signatures = methods
+                .iter()
+                .map(|&method| synthesizer.resolve_method_signature(method))
+                .collect::<SemanticResult<Vec<_>>>()?;
+
+            Ok((field_types, method_signatures))
+        })?;
+
+        let self_type = self.self_type_id(user_type, &type_params);
+
+        let constructor_params = field_types
+            .iter()
+            .map(|&(field_name, field_type)| ParamSignature::new(Some(field_name), field_type, false))
+            .collect();
+        let constructor = FunctionSignature::new(type_params.clone(), constructor_params, self_type);
+        let constructor_type = self.ctx.type_arena.function_type_id(constructor.param_types(), self_type);
+        self.ctx.symbol_table.add_symbol(scope, Symbol::function(name, constructor_type, constructor, span));
+
+        let user_type = self.ctx.type_arena.user_type_mut(user_type);
+        user_type.type_params = type_params;
+        user_type.kind = UserTypeKind::Struct { fields: field_types };
+        user_type.methods.extend(method_signatures);
+
+        Ok(())
+    }
+
+    fn define_enum(
+        &mut self,
+        name: DefaultSymbol,
+        type_params: Vec<TypeParam>,
+        variants: Vec<VariantDef>,
+        enum_node: ASTNodeId,
+    ) -> SemanticResult<()> {
+        let node = self.ast.lookup(enum_node);
+        let (span, scope) = (node.span, node.scope);
+
+        let user_type = self.lookup_user_type(name, scope).expect("User type must be declared before it is defined");
+        let type_params = self.resolve_type_params(&type_params, scope)?;
+
+        let variant_types = self.with_type_params(&type_params, |synthesizer| {
+            variants
+                .iter()
+                .map(|variant| {
+                    let payload = variant.payload
+                        .iter()
+                        .map(|payload_type| synthesizer.resolve_annotation(payload_type, scope))
+                        .collect::<SemanticResult<Vec<_>>>()?;
+                    Ok((variant.name, payload))
+                })
+                .collect::<SemanticResult<Vec<_>>>()
+        })?;
+
+        let self_type = self.self_type_id(user_type, &type_params);
+
+        for (variant_name, payload) in &variant_types {
+            let symbol = if payload.is_empty() {
+                Symbol::generic(*variant_name, self_type, type_params.clone(), span)
+            } else {
+                let constructor = FunctionSignature {
+                    type_params: type_params.clone(),
+                    ..FunctionSignature::positional(payload.clone(), self_type)
+                };
+                let constructor_type = self.ctx.type_arena.function_type_id(payload.clone(), self_type);
+                Symbol::function(*variant_name, constructor_type, constructor, span)
+            };
+
+            self.ctx.symbol_table.add_symbol(scope, symbol);
+        }
+
+        let user_type = self.ctx.type_arena.user_type_mut(user_type);
+        user_type.type_params = type_params;
+        user_type.kind = UserTypeKind::Enum { variants: variant_types };
+
+        Ok(())
+    }
+
+    fn define_trait(&mut self, name: DefaultSymbol, methods: Vec<ASTNodeId>, trait_node: ASTNodeId) -> SemanticResult<()> {
+        let node = self.ast.lookup(trait_node);
+        let (span, scope) = (node.span, node.scope);
+
+        let trait_id = self.lookup_trait(name, scope).expect("Trait must be declared before it is defined");
+        let self_param = TypeParam::new(self.ctx.get_symbol("Self"), Vec::new(), span);
+
+        let method_signatures = self.with_type_params(&[self_param], |synthesizer| {
+            methods
+                .iter()
+                .map(|&method| synthesizer.resolve_method_signature(method))
+                .collect::<SemanticResult<Vec<_>>>()
+        })?;
+
+        self.ctx.type_arena.trait_def_mut(trait_id).methods = method_signatures;
+
+        Ok(())
+    }
+
+    fn define_user_type(&mut self, node_id: ASTNodeId) -> SemanticResult<()> {
+        match &self.ast.lookup(node_id).node_data_type {
+            ASTNodeType::StructDef(struct_def) => self.define_struct(
+                struct_def.name,
+                struct_def.type_params.clone(),
+                struct_def.fields.clone(),
+                struct_def.methods.clone(),
+                node_id,
+            ),
+            ASTNodeType::EnumDef(enum_def) => self.define_enum(
+                enum_def.name,
+                enum_def.type_params.clone(),
+                enum_def.variants.clone(),
+                node_id,
+            ),
+            ASTNodeType::TraitDef(trait_def) => self.define_trait(trait_def.name, trait_def.methods.clone(), node_id),
+            _ => Ok(()),
+        }
+    }
+
+    fn signature_type_id(&mut self, signature: &FunctionSignature) -> DataTypeId {
+        self.ctx.type_arena.function_type_id(signature.param_types(), signature.return_type)
+    }
+
+    fn define_impl(&mut self, node_id: ASTNodeId) -> SemanticResult<()> {
+        let node = self.ast.lookup(node_id);
+
+        let impl_node = match &node.node_data_type {
+            ASTNodeType::Impl(impl_node) => impl_node,
+            _ => return Ok(()),
+        };
+
+        let (trait_name, type_name, span, scope) = (impl_node.trait_name, impl_node.type_name, node.span, node.scope);
+        let type_params = impl_node.type_params.clone();
+        let methods = impl_node.methods.clone();
+
+        let trait_name_str = self.ctx.get_str(trait_name).unwrap_or_default().to_string();
+        let type_name_str = self.ctx.get_str(type_name).unwrap_or_default().to_string();
+
+        let trait_id = match self.lookup_trait(trait_name, scope) {
+            Some(trait_id) => trait_id,
+            None => return Err(UnknownTrait(trait_name_str).at(span)),
+        };
+        let trait_def = self.ctx.type_arena.trait_def(trait_id).clone();
+
+        let user_type_id = match self.lookup_user_type(type_name, scope) {
+            Some(user_type_id) => user_type_id,
+            None => return Err(UnknownType(type_name_str).at(span)),
+        };
+
+        let type_params = self.resolve_type_params(&type_params, scope)?;
+        let user_type = self.ctx.type_arena.user_type(user_type_id);
+
+        if user_type.type_params.len() != type_params.len() {
+            return Err(WrongTypeArgumentCount {
+                type_name: type_name_str,
+                expected: user_type.type_params.len(),
+                found: type_params.len(),
+            }.at(span));
+        }
+
+        if user_type.traits.contains_key(&trait_id) {
+            return Err(DuplicateImpl {
+                trait_name: trait_name_str,
+                type_name: type_name_str,
+            }.at(span));
+        }
+
+        let defined_type_params = user_type.type_params.clone();
+        let mut defined_methods = user_type.methods.keys().copied().collect::<Vec<_>>();
+
+        // The impl header may name the type parameters differently from the type's definition
+        let renaming = type_params
+            .iter()
+            .zip(&defined_type_params)
+            .map(|(impl_param, defined_param)| (impl_param.name, self.ctx.type_arena.type_param_id(defined_param.name)))
+            .collect();
+        let self_type = self.self_type_id(user_type_id, &defined_type_params);
+        let self_binding = HashMap::from([(self.ctx.get_symbol("Self"), self_type)]);
+
+        let mut impl_methods = Vec::new();
+
+        for method in methods {
+            let (method_name, signature) = self.with_type_params(&type_params, |synthesizer| {
+                synthesizer.resolve_method_signature(method)
+            })?;
+            let signature = self.substitute_signature(&signature, &renaming);
+            let method_span = self.ast.lookup(method).span;
+            let method_name_str = self.ctx.get_str(method_name).unwrap_or_default().to_string();
+
+            let expected = match trait_def.method(method_name) {
+                Some(expected) => self.substitute_signature(expected, &self_binding),
+                None => return Err(UnknownTraitMethod {
+                    trait_name: trait_name_str,
+                    method: method_name_str,
+                }.at(method_span)),
+            };
+
+            let expected_type = self.signature_type_id(&expected);
+            let found_type = self.signature_type_id(&signature);
+
+            if expected_type != found_type || expected.type_params.len() != signature.type_params.len() {
+                return Err(MismatchedTraitMethod {
+                    trait_name: trait_name_str,
+                    method: method_name_str,
+                    expected: self.display_type(Some(expected_type)),
+                    found: self.display_type(Some(found_type)),
+                }.at(method_span));
+            }
+
+            if defined_methods.contains(&method_name) {
+                return Err(DuplicateMethod(method_name_str).at(method_span));
+            }
+
+            defined_methods.push(method_name);
+            impl_methods.push((method_name, signature));
+        }
+
+        if let Some((missing, _)) = trait_def.methods.iter().find(|(name, _)| impl_methods.iter().all(|(method_name, _)| method_name != name)) {
+            return Err(MissingTraitMethod {
+                trait_name: trait_name_str,
+                type_name: type_name_str,
+                method: self.ctx.get_str(*missing).unwrap_or_default().to_string(),
+            }.at(span));
+        }
+
+        let user_type = self.ctx.type_arena.user_type_mut(user_type_id);
+        user_type.methods.extend(impl_methods);
+        user_type.traits.insert(trait_id, type_params);
+
+        Ok(())
+    }
+
+    fn check_exported(&self, module: ModuleId, name: DefaultSymbol, span: SourceSpan) -> SemanticResult<()> {
+        let module = self.ctx.modules.module(module);
+
+        if module.exports.contains(&name) {
+            return Ok(());
+        }
+
+        let is_declared = self.ctx.symbol_table.lookup(name, module.scope).is_some()
+            || self.ctx.symbol_table.lookup_type(name, module.scope).is_some();
+
+        let module_name = self.ctx.get_str(module.name).unwrap_or_default().to_string();
+        let name = self.ctx.get_str(name).unwrap_or_default().to_string();
+
+        if is_declared {
+            Err(NotExported { module: module_name, name }.at(span))
+        } else {
+            Err(UnknownModuleMember { module: module_name, name }.at(span))
+        }
+    }
+
+    fn import_name(&mut self, module: ModuleId, imported: &ImportedName, scope: ScopeId) -> SemanticResult<()> {
+        self.check_exported(module, imported.name, imported.span)?;
+
+        let module_scope = self.ctx.modules.module(module).scope;
+
+        let mut names = vec![imported.name];
+
+        if let Some(named_type) = self.ctx.symbol_table.lookup_type(imported.name, module_scope) {
+            self.ctx.symbol_table.add_type(scope, imported.name, named_type);
+
+            if let NamedType::UserType(user_type) = named_type
+                && let UserTypeKind::Enum { variants } = &self.ctx.type_arena.user_type(user_type).kind {
+                names.extend(variants.iter().map(|&(variant_name, _)| variant_name));
+            }
+        }
+
+        for name in names {
+            if let Some(symbol) = self.ctx.symbol_table.lookup(name, module_scope) {
+                let symbol = symbol.clone().with_def_span(imported.span);
+                self.ctx.symbol_table.add_symbol(scope, symbol);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn declare_import(&mut self, node_id: ASTNodeId) -> SemanticResult<()> {
+        let node = self.ast.lookup(node_id);
+
+        let import = match &node.node_data_type {
+            ASTNodeType::Import(import) => import,
+            _ => return Ok(()),
+        };
+
+        let (span, scope) = (node.span, node.scope);
+        let module = import.module.expect("Imported modules are resolved before type synthesis");
+
+        match import.names.clone() {
+            Some(names) => {
+                for imported in &names {
+                    self.import_name(module, imported, scope)?;
+                }
+            },
+            None => {
+                let name = *import.path.last().expect("Module path cannot be empty");
+                let module_type = self.ctx.type_arena.module_type_id(module);
+                let symbol = Symbol::new(name, module_type, span).with_mutability(Mutability::Immutable);
+                self.ctx.symbol_table.add_symbol(scope, symbol);
+            },
+        }
+
+        Ok(())
+    }
+
+    fn compute_module_member_type(&mut self, module: ModuleId, member: Member, access_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        let span = self.ast.lookup(access_node).span;
+        let module_scope = self.ctx.modules.module(module).scope;
+
+        let name = match &member {
+            Member::Field { name } | Member::Method { name, .. } => *name,
+        };
+
+        self.check_exported(module, name, span)?;
+
+        match member {
+            Member::Field { .. } => {
+                let node = self.ast.lookup_mut(access_node);
+                node.node_data_type = ASTNodeType::Variable(VariableNode::new(name, None));
+                node.scope = module_scope;
+
+                match self.compute_variable_type(name, module_scope, span)? {
+                    Some(data_type) => Ok(data_type),
+                    None => {
+                        let module_name = self.ctx.get_str(self.ctx.modules.module(module).name).unwrap_or_default().to_string();
+                        let name = self.ctx.get_str(name).unwrap_or_default().to_string();
+                        Err(UnknownModuleMember { module: module_name, name }.at(span))
+                    },
+                }
+            },
+
+            Member::Method { args, .. } => {
+                let function = self.ast.add_node(VariableNode::new(name, None).at(span, module_scope));
+                let call = FunctionCallNode::new(function, args.clone());
+                self.ast.lookup_mut(access_node).node_data_type = ASTNodeType::FunctionCall(call);
+
+                self.compute_function_call_type(function, args, access_node)
+            },
+        }
+    }
+
+    fn register_iter_method(&mut self, node_id: ASTNodeId) {
+        let node = self.ast.lookup(node_id);
+
+        let type_name = match &node.node_data_type {
+            ASTNodeType::StructDef(struct_def) => struct_def.name,
+            ASTNodeType::EnumDef(enum_def) => enum_def.name,
+            _ => return,
+        };
+
+        let user_type = self.lookup_user_type(type_name, node.scope).expect("User type must be declared before it is defined");
+        let iter = self.ctx.get_symbol("iter");
+
+        let return_type = match self.ctx.type_arena.user_type(user_type).methods.get(&iter) {
+            Some(method) if method.params.len() == 1 && method.type_params.is_empty() => method.return_type,
+            _ => return,
+        };
+
+        if let Some(item_type) = self.iterable_impl.resolve_item_type(return_type, &mut self.ctx.type_arena) {
+            self.iterable_impl.register_iter_method(user_type, item_type);
+        }
+    }
+
+    fn compute_block_types(&mut self, block: &[ASTNodeId]) -> SemanticResult<()> {
+        for &node_id in block {
+            self.declare_import(node_id)?;
+        }
+
+        for &node_id in block {
+            self.declare_user_type(node_id)?;
+        }
+
+        for &node_id in block {
+            self.define_user_type(node_id)?;
+        }
+
+        for &node_id in block {
+            self.define_impl(node_id)?;
+        }
+
+        for &node_id in block {
+            self.register_iter_method(node_id);
+        }
+
+        for &node_id in block {
+            self.declare_function(node_id)?;
+        }
+
+        self.compute_types(block)
+    }
+
+    fn check_condition_type(&mut self, condition: ASTNodeId) -> SemanticResult<()> {
+        self.compute_type(condition)?;
+
+        let condition_node = self.ast.lookup(condition);
+        let bool_type = self.ctx.type_arena.builtin_type_id(Bool);
+
+        if let Some(data_type) = condition_node.data_type && self.ctx.type_arena.unify(data_type, bool_type, condition_node.span) {
+            return Ok(());
+        }
+
+        let suggestion = match condition_node.data_type.map(|data_type| self.ctx.type_arena.find(data_type)) {
+            Some(data_type) => match self.ctx.type_arena.get(data_type) {
+                Builtin(Int) => Some("!= 0"),
+                Builtin(String) => Some("!= \"\""),
+                _ => None,
+            },
+            None => None,
+        };
+
+        let found = self.display_type(condition_node.data_type);
+
+        match suggestion {
+            Some(suggestion) => Err(NonBoolComparableCondition { found, suggestion }.at(condition_node.span)),
+            None => Err(NonBoolCondition(found).at(condition_node.span)),
+        }
+    }
+
+    fn compute_tuple_type(&mut self, elements: Vec<ASTNodeId>) -> SemanticResult<Option<DataTypeId>> {
+        self.compute_types(&elements)?;
+
+        let element_types = elements
+            .iter()
+            .map(|&element| self.ast.lookup(element).data_type)
+            .collect::<Option<Vec<DataTypeId>>>();
+
+        Ok(element_types.map(|element_types| self.ctx.type_arena.tuple_type_id(element_types)))
+    }
+
+    fn compute_list_type(&mut self, elements: Vec<ASTNodeId>) -> SemanticResult<DataTypeId> {
+        self.compute_types(&elements)?;
+
+        let element_type = self.ctx.type_arena.fresh_type_var();
+
+        for &element in &elements {
+            let element_node = self.ast.lookup(element);
+
+            self.expect_type(element_type, element_node.data_type, element_node.span, |expected, found| {
+                MismatchedListElement { expected, found }
+            })?;
+        }
+
+        Ok(self.ctx.type_arena.list_type_id(element_type))
+    }
+
+    fn compute_map_type(&mut self, entries: Vec<MapEntry>, map_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        for entry in &entries {
+            self.compute_type(entry.key)?;
+            self.compute_type(entry.value)?;
+        }
+
+        let key_type = self.ctx.type_arena.fresh_type_var();
+        let value_type = self.ctx.type_arena.fresh_type_var();
+
+        for entry in &entries {
+            let key_node = self.ast.lookup(entry.key);
+            let (found_key_type, key_span) = (key_node.data_type, key_node.span);
+
+            self.expect_type(key_type, found_key_type, key_span, |expected, found| {
+                MismatchedMapKey { expected, found }
+            })?;
+
+            let value_node = self.ast.lookup(entry.value);
+            self.expect_type(value_type, value_node.data_type, value_node.span, |expected, found| {
+                MismatchedMapValue { expected, found }
+            })?;
+        }
+
+        let key_span = match entries.first() {
+            Some(entry) => self.ast.lookup(entry.key).span,
+            None => self.ast.lookup(map_node).span,
+        };
+
+        self.require_hashable(key_type, key_span)?;
+
+        Ok(self.ctx.type_arena.map_type_id(key_type, value_type))
+    }
+
+    fn compute_index_type(&mut self, operand: ASTNodeId, args: Vec<Argument>, index_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(operand)?;
+
+        let arg = match args.as_slice() {
+            [arg] => match arg.keyword {
+                None => *arg,
+                Some(keyword) => return Err(UnknownKeywordArgument(
+                    self.ctx.get_str(keyword).unwrap_or_default().to_string()
+                ).at(arg.span)),
+            },
+            _ => return Err(WrongArgumentCount {
+                expected: 1,
+                found: args.len(),
+            }.at(args.get(1).map_or(self.ast.lookup(index_node).span, |arg| arg.span))),
+        };
+
+        self.compute_type(arg.value)?;
+
+        let operand_node = self.ast.lookup(operand);
+        let arg_type = self.ast.lookup(arg.value).data_type;
+
+        let type_arena = &self.ctx.type_arena;
+
+        let (index_type, item_type) = match operand_node.data_type.map(|id| type_arena.get(type_arena.find(id))) {
+            Some(DataType::List(element_type)) => (type_arena.builtin_type_id(Int), *element_type),
+            Some(DataType::Map(key_type, value_type)) => (*key_type, *value_type),
+            _ => return Err(NotIndexable(self.display_type(operand_node.data_type)).at(operand_node.span)),
+        };
+
+        self.expect_type(index_type, arg_type, arg.span, |expected, found| {
+            MismatchedIndexType { expected, found }
+        })?;
+
+        Ok(item_type)
+    }
+
+    fn compute_cast_type(&mut self, operand: ASTNodeId, target: TypeAnnotation, cast_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(operand)?;
+
+        let node = self.ast.lookup(cast_node);
+        let (span, scope) = (node.span, node.scope);
+        let target_type = self.resolve_annotation(&target, scope)?;
+
+        let operand_type = match self.resolved_type(operand) {
+            // A cast of a value that is not fully inferred yet can only be the identity cast
+            Some(operand_type) if self.ctx.type_arena.contains_unsolved(operand_type) => {
+                if self.ctx.type_arena.unify(operand_type, target_type, span) {
+                    return Ok(target_type);
+                }
+                Some(operand_type)
+            },
+            Some(operand_type) if is_legal_cast(operand_type, target_type, &self.ctx.type_arena) => {
+                return Ok(target_type);
+            },
+            operand_type => operand_type,
+        };
+
+        let is_user_type = |data_type: DataTypeId| matches!(self.ctx.type_arena.get(data_type), DataType::UserDefined(..));
+        let user_type_involved = operand_type.is_some_and(is_user_type) || is_user_type(target_type);
+
+        let (from, to) = (self.display_type(operand_type), self.display_type(Some(target_type)));
+
+        if user_type_involved {
+            Err(UserTypeCast { from, to }.at(span))
+        } else {
+            Err(InvalidCast { from, to }.at(span))
+        }
+    }
+
+    fn has_to_string_method(&mut self, data_type: DataTypeId) -> bool {
+        let to_string = self.ctx.get_symbol("to_string");
+
+        match self.ctx.type_arena.get(data_type) {
+            &DataType::TypeParam(name) => {
+                let bounds = self.type_param_bounds(name);
+                self.method_impl.resolve_bound_method(data_type, &bounds, to_string, self.ctx).is_some()
+            },
+            DataType::UserDefined(..) => self.method_impl.resolve_method(data_type, to_string, self.ctx).is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_formattable(&mut self, data_type: DataTypeId) -> bool {
+        matches!(self.ctx.type_arena.get(data_type), Builtin(String | Int | Bool)) || self.has_to_string_method(data_type)
+    }
+
+    fn not_formattable(&self, data_type: DataTypeId) -> SemanticError {
+        match self.ctx.type_arena.get(data_type) {
+            DataType::UserDefined(..) => MissingToString(self.display_type(Some(data_type))),
+            _ => NotFormattable(self.display_type(Some(data_type))),
+        }
+    }
+
+    fn format_interpolated_part(&mut self, part: ASTNodeId) -> SemanticResult<ASTNodeId> {
+        self.compute_type(part)?;
+        self.expect_defined(part)?;
+
+        let node = self.ast.lookup(part);
+        let (span, scope) = (node.span, node.scope);
+        let string_type = self.ctx.type_arena.builtin_type_id(String);
+
+        let part_type = match self.resolved_type(part) {
+            Some(part_type) => part_type,
+            None => return Err(NotFormattable(self.display_type(None)).at(span)),
+        };
+
+        // A value that is not inferred yet is taken to be a string
+        if self.ctx.type_arena.contains_unsolved(part_type) && self.ctx.type_arena.unify(part_type, string_type, span) {
+            return Ok(part);
+        }
+
+        let formatted = match self.ctx.type_arena.get(part_type).clone() {
+            Builtin(String) => return Ok(part),
+            Builtin(Int | Bool) => {
+                let target = TypeAnnotation::new(self.ctx.get_symbol("String"), span);
+                CastNode::new(part, target).at(span, scope)
+            },
+            _ if self.has_to_string_method(part_type) => {
+                let to_string = self.ctx.get_symbol("to_string");
+                AccessNode::new(part, Member::method(to_string, Vec::new())).at(span, scope)
+            },
+            _ => return Err(self.not_formattable(part_type).at(span)),
+        };
+
+        let formatted = self.ast.add_node(formatted);
+        self.compute_type(formatted)?;
+
+        let formatted_type = self.ast.lookup(formatted).data_type;
+        self.expect_type(string_type, formatted_type, span, |_, found| InvalidToStringReturn(found))?;
+
+        Ok(formatted)
+    }
+
+    fn compute_interpolated_string_type(&mut self, parts: Vec<ASTNodeId>, string_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        let node = self.ast.lookup(string_node);
+        let (span, scope) = (node.span, node.scope);
+        let string_type = self.ctx.type_arena.builtin_type_id(String);
+
+        let mut parts = parts
+            .into_iter()
+            .map(|part| self.format_interpolated_part(part))
+            .collect::<SemanticResult<Vec<_>>>()?
+            .into_iter();
+
+        let first = parts.next().expect("Interpolated strings must start with a string literal");
+        let last = parts.next_back().expect("Interpolated strings must embed an expression");
+
+        let mut concatenation = first;
+
+        for part in parts {
+            let add = BinaryOperatorNode::new(BinaryOperator::Add, concatenation, part);
+            concatenation = self.ast.add_node(add.at(span, scope));
+            self.ast.lookup_mut(concatenation).data_type = Some(string_type);
+        }
+
+        let add = BinaryOperatorNode::new(BinaryOperator::Add, concatenation, last);
+        self.ast.lookup_mut(string_node).node_data_type = ASTNodeType::BinaryOperator(add);
+
+        Ok(string_type)
+    }
+
+    fn param_display_name(&self, signature: &FunctionSignature, index: usize) -> std::string::String {
+        match signature.params[index].name {
+            Some(name) => format!("'{}'", self.ctx.get_str(name).unwrap_or_default()),
+            None => format!("#{}", index + 1),
+        }
+    }
+
+    fn check_call_args(&mut self, signature: &FunctionSignature, args: &[Argument], call_span: SourceSpan) -> SemanticResult<DataTypeId> {
+        let params = &signature.params;
+        let mut bound = vec![false; params.len()];
+        let mut bound_args = Vec::new();
+
+        for (position, arg) in args.iter().enumerate() {
+            let param_index = match arg.keyword {
+                None if position < params.len() => position,
+                None => return Err(WrongArgumentCount {
+                    expected: params.len(),
+                    found: args.len(),
+                }.at(arg.span)),
+
+                Some(keyword) => match params.iter().position(|param| param.name == Some(keyword)) {
+                    Some(param_index) => param_index,
+                    None => return Err(UnknownKeywordArgument(
+                        self.ctx.get_str(keyword).unwrap_or_default().to_string()
+                    ).at(arg.span)),
+                },
+            };
+
+            if bound[param_index] {
+                return Err(DuplicateArgument(self.param_display_name(signature, param_index)).at(arg.span));
+            }
+            bound[param_index] = true;
+
+            self.compute_type(arg.value)?;
+            self.expect_defined(arg.value)?;
+            bound_args.push((param_index, *arg));
+        }
+
+        let missing = params
+            .iter()
+            .zip(&bound)
+            .position(|(param, &bound)| !bound && !param.has_default);
+
+        if let Some(param_index) = missing {
+            return Err(MissingArgument(self.param_display_name(signature, param_index)).at(call_span));
+        }
+
+        for (param_index, arg) in bound_args {
+            let arg_type = self.ast.lookup(arg.value).data_type;
+
+            self.expect_type(params[param_index].data_type, arg_type, arg.span, |expected, found| {
+                MismatchedArgumentType { expected, found }
+            })?;
+        }
+
+        Ok(signature.return_type)
+    }
+
+    /// Variables that are not declared are left without a type, so that assignments can declare them.
+    fn expect_defined(&self, node_id: ASTNodeId) -> SemanticResult<()> {
+        let node = self.ast.lookup(node_id);
+
+        match &node.node_data_type {
+            ASTNodeType::Variable(var) if node.data_type.is_none() => {
+                Err(UnknownName(self.ctx.get_str(var.name).unwrap_or_default().to_string()).at(node.span))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn compute_function_call_type(&mut self, function: ASTNodeId, args: Vec<Argument>, call_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(function)?;
+        self.expect_defined(function)?;
+
+        let function_node = self.ast.lookup(function);
+
+        let declared_signature = match &function_node.node_data_type {
+            ASTNodeType::Variable(variable) => self.ctx.symbol_table
+                .lookup(variable.name, function_node.scope)
+                .and_then(|symbol| symbol.signature.clone()),
+            _ => None,
+        };
+
+        let call_span = self.ast.lookup(call_node).span;
+        let type_arena = &self.ctx.type_arena;
+
+        let signature = match (declared_signature, function_node.data_type.map(|id| type_arena.get(type_arena.find(id)))) {
+            (Some(signature), _) => {
+                // The callee gets the same instantiation as the call so its type is fully solved
+                let signature = self.instantiate_signature(&signature, call_span)?;
+                let function_type = self.ctx.type_arena.function_type_id(signature.param_types(), signature.return_type);
+                self.ast.lookup_mut(function).data_type = Some(function_type);
+                signature
+            },
+            (None, Some(DataType::Function(param_types, return_type))) => {
+                FunctionSignature::positional(param_types.clone(), *return_type)
+            },
+            _ => return Err(NotCallable(self.display_type(function_node.data_type)).at(function_node.span)),
+        };
+
+        let return_type = self.check_call_args(&signature, &args, call_span)?;
+        self.check_bound_obligations(false)?;
+
+        Ok(return_type)
+    }
+
+    fn compute_method_call_type(
+        &mut self,
+        receiver_type: Option<DataTypeId>,
+        method_name: DefaultSymbol,
+        args: Vec<Argument>,
+        access_node: ASTNodeId,
+    ) -> SemanticResult<DataTypeId> {
+        let span = self.ast.lookup(access_node).span;
+
+        let signature = match receiver_type.map(|id| self.ctx.type_arena.find(id)) {
+            Some(receiver_type) => match self.ctx.type_arena.get(receiver_type) {
+                DataType::TypeParam(name) => {
+                    let bounds = self.type_param_bounds(*name);
+                    self.method_impl.resolve_bound_method(receiver_type, &bounds, method_name, self.ctx)
+                },
+                _ => {
+                    self.require_impl_bounds(receiver_type, method_name, span);
+                    self.method_impl.resolve_method(receiver_type, method_name, self.ctx)
+                },
+            },
+            None => None,
+        };
+
+        let signature = match signature {
+            Some(signature) => self.instantiate_signature(&signature, span)?,
+            None => return Err(UnknownMethod {
+                receiver: self.display_type(receiver_type),
+                method: self.ctx.get_str(method_name).unwrap_or_default().to_string(),
+            }.at(span)),
+        };
+
+        let return_type = self.check_call_args(&signature, &args, span)?;
+        self.check_bound_obligations(false)?;
+
+        Ok(return_type)
+    }
+
+    fn require_impl_bounds(&mut self, receiver_type: DataTypeId, method_name: DefaultSymbol, span: SourceSpan) {
+        let type_arena = &self.ctx.type_arena;
+
+        let implemented_trait = match type_arena.get(receiver_type) {
+            DataType::UserDefined(user_type, _) => type_arena.user_type(*user_type).traits
+                .keys()
+                .copied()
+                .find(|&trait_id| type_arena.trait_def(trait_id).method(method_name).is_some()),
+            _ => None,
+        };
+
+        if let Some(trait_id) = implemented_trait {
+            self.bound_obligations.push(BoundObligation {
+                data_type: receiver_type,
+                bound: Bound::Trait(trait_id),
+                type_params_in_scope: self.type_params.clone(),
+                span,
+            });
+        }
+    }
+
+    fn type_param_bounds(&self, name: DefaultSymbol) -> Vec<TraitId> {
+        self.type_params
+            .iter()
+            .rev()
+            .find(|type_param| type_param.name == name)
+            .map(|type_param| type_param.traits.clone())
+            .unwrap_or_default()
+    }
+
+    fn compute_access_type(&mut self, receiver: ASTNodeId, member: Member, access_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.compute_type(receiver)?;
+
+        let receiver_type = self.ast.lookup(receiver).data_type.map(|id| self.ctx.type_arena.find(id));
+
+        if let Some(receiver_type) = receiver_type
+            && let &DataType::Module(module) = self.ctx.type_arena.get(receiver_type) {
+            return self.compute_module_member_type(module, member, access_node);
+        }
+
+        match member {
+            Member::Field { name } => {
+                let field_type = receiver_type.and_then(|receiver_type| {
+                    let (user_type, bindings) = self.ctx.type_arena.user_type_bindings(receiver_type)?;
+                    Some((user_type.field_type(name)?, bindings))
+                });
+
+                match field_type {
+                    Some((field_type, bindings)) => Ok(self.ctx.type_arena.substitute(field_type, &bindings)),
+                    None => Err(UnknownField {
+                        receiver: self.display_type(receiver_type),
+                        field: self.ctx.get_str(name).unwrap_or_default().to_string(),
+                    }.at(self.ast.lookup(access_node).span)),
+                }
+            },
+
+            Member::Method { name, args } => {
+                self.compute_method_call_type(receiver_type, name, args, access_node)
+            },
+        }
+    }
+
+    fn compute_function_def_type(
+        &mut self,
+        name: DefaultSymbol,
+        params: Vec<Parameter>,
+        body: Vec<ASTNodeId>,
+        body_scope: ScopeId,
+        func_def_node: ASTNodeId,
+    ) -> SemanticResult<DataTypeId> {
+        let node = self.ast.lookup(func_def_node);
+        let def_span = node.span;
+
+        let signature = self.ctx.symbol_table
+            .lookup(name, node.scope)
+            .and_then(|symbol| symbol.signature.clone())
+            .expect("Function must be declared before its body is checked");
+
+        self.check_function_body(signature, params, body, body_scope, def_span)?;
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn check_function_body(
+        &mut self,
+        signature: FunctionSignature,
+        params: Vec<Parameter>,
+        body: Vec<ASTNodeId>,
+        body_scope: ScopeId,
+        def_span: SourceSpan,
+    ) -> SemanticResult<()> {
+        for (param, param_type) in params.into_iter().zip(signature.param_types()) {
+            self.ctx.symbol_table.add_symbol(body_scope, Symbol::new(param.name, param_type, def_span));
+        }
+
+        self.with_type_params(&signature.type_params, |synthesizer| {
+            synthesizer.return_types.push(signature.return_type);
+            let body_result = synthesizer.compute_block_types(&body);
+            synthesizer.return_types.pop();
+            body_result
+        })
+    }
+
+    fn compute_struct_def_type(&mut self, name: DefaultSymbol, methods: Vec<ASTNodeId>, struct_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        let scope = self.ast.lookup(struct_node).scope;
+        let user_type = self.lookup_user_type(name, scope).expect("User type must be declared before its methods are checked");
+        let type_params = self.ctx.type_arena.user_type(user_type).type_params.clone();
+
+        for method in methods {
+            let method_name = match &self.ast.lookup(method).node_data_type {
+                ASTNodeType::FunctionDef(func_def) => func_def.name,
+                _ => unreachable!("Struct methods must be function definitions"),
+            };
+
+            let signature = self.ctx.type_arena
+                .user_type(user_type)
+                .methods
+                .get(&method_name)
+                .cloned()
+                .expect("Methods must be declared before their bodies are checked");
+
+            self.check_method_body(method, signature, &type_params)?;
+        }
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn compute_impl_type(&mut self, type_params: Vec<TypeParam>, methods: Vec<ASTNodeId>, impl_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        let type_params = self.resolve_type_params(&type_params, self.ast.lookup(impl_node).scope)?;
+
+        for method in methods {
+            // Checked against the signature as written, in terms of the impl's own type parameters
+            let (_, signature) = self.with_type_params(&type_params, |synthesizer| {
+                synthesizer.resolve_method_signature(method)
+            })?;
+
+            self.check_method_body(method, signature, &type_params)?;
+        }
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn check_method_body(&mut self, method: ASTNodeId, signature: FunctionSignature, type_params: &[TypeParam]) -> SemanticResult<()> {
+        let method_node = self.ast.lookup(method);
+
+        let (params, body, body_scope) = match &method_node.node_data_type {
+            ASTNodeType::FunctionDef(func_def) => (func_def.params.clone(), func_def.body.clone(), func_def.body_scope),
+            _ => unreachable!("Methods must be function definitions"),
+        };
+        let def_span = method_node.span;
+
+        self.with_type_params(type_params, |synthesizer| {
+            synthesizer.check_function_body(signature, params, body, body_scope, def_span)
+        })?;
+
+        self.ast.lookup_mut(method).data_type = Some(self.ctx.type_arena.builtin_type_id(Unit));
+        Ok(())
+    }
+
+    fn compute_return_type(&mut self, value: Option<ASTNodeId>, return_node: ASTNodeId) -> SemanticResult<DataTypeId> {
+        let span = self.ast.lookup(return_node).span;
+
+        let expected_type = match self.return_types.last() {
+            Some(&expected_type) => expected_type,
+            None => return Err(ReturnOutsideFunction.at(span)),
+        };
+
+        let (found_type, span) = match value {
+            Some(value) => {
+                self.compute_type(value)?;
+                let value_node = self.ast.lookup(value);
+                (value_node.data_type, value_node.span)
+            },
+            None => (Some(self.ctx.type_arena.builtin_type_id(Unit)), span),
+        };
+
+        self.expect_type(expected_type, found_type, span, |expected, found| {
+            MismatchedReturnType { expected, found }
+        })?;
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn compute_if_type(&mut self, condition_blocks: Vec<ConditionBlock>, else_body: Option<Vec<ASTNodeId>>) -> SemanticResult<DataTypeId> {
+        for block in &condition_blocks {
+            self.check_condition_type(block.condition)?;
+            self.compute_block_types(&block.body)?;
+        }
+
+        if let Some(else_body) = else_body {
+            self.compute_block_types(&else_body)?;
+        }
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn compute_conditional_type(&mut self, condition: ASTNodeId, then_value: ASTNodeId, else_value: ASTNodeId) -> SemanticResult<DataTypeId> {
+        self.check_condition_type(condition)?;
+        self.compute_type(then_value)?;
+        self.compute_type(else_value)?;
+
+        let value_type = self.ctx.type_arena.fresh_type_var();
+
+        for branch in [then_value, else_value] {
+            let branch_node = self.ast.lookup(branch);
+
+            self.expect_type(value_type, branch_node.data_type, branch_node.span, |expected, found| {
+                MismatchedConditionalBranches { expected, found }
+            })?;
+        }
+
+        Ok(value_type)
+    }
+
+    fn compute_while_type(&mut self, condition: ASTNodeId, body: Vec<ASTNodeId>) -> SemanticResult<DataTypeId> {
+        self.check_condition_type(condition)?;
+        self.compute_block_types(&body)?;
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn bind_for_items(&mut self, item_identifiers: Vec<DefaultSymbol>, item_type: DataTypeId, body_scope: ScopeId, for_node: ASTNodeId) -> SemanticResult<()> {
+        let def_span = self.ast.lookup(for_node).span;
+
+        let type_arena = &self.ctx.type_arena;
+
+        let item_types = match (item_identifiers.len(), type_arena.get(type_arena.find(item_type))) {
+            (1, _) => vec![item_type],
+            (count, DataType::Tuple(element_types)) if count == element_types.len() => element_types.clone(),
+            (count, _) => return Err(MismatchedDestructure {
+                expected: count,
+                found: self.display_type(Some(item_type)),
+            }.at(def_span)),
+        };
+
+        for (name, data_type) in item_identifiers.into_iter().zip(item_types) {
+            self.ctx.symbol_table.add_symbol(body_scope, Symbol::new(name, data_type, def_span));
+        }
+
+        Ok(())
+    }
+
+    fn compute_for_type(
+        &mut self,
+        item_identifiers: Vec<DefaultSymbol>,
+        iterator: ASTNodeId,
+        body: Vec<ASTNodeId>,
+        body_scope: ScopeId,
+        for_node: ASTNodeId,
+    ) -> SemanticResult<DataTypeId> {
+        self.compute_type(iterator)?;
+
+        let iterator_type = self.ast.lookup(iterator).data_type;
+
+        let item_type = match iterator_type {
+            Some(iterator_type) => {
+                let iterator_type = self.ctx.type_arena.find(iterator_type);
+                self.iterable_impl.resolve_item_type(iterator_type, &mut self.ctx.type_arena)
+            },
+            None => None,
+        };
+
+        let item_type = match item_type {
+            Some(item_type) => item_type,
+            None => {
+                let found = self.display_type(iterator_type);
+                return Err(NotIterable(found).at(self.ast.lookup(iterator).span));
+            }
+        };
+
+        self.bind_for_items(item_identifiers, item_type, body_scope, for_node)?;
+
+        self.compute_block_types(&body)?;
+
+        Ok(self.ctx.type_arena.builtin_type_id(Unit))
+    }
+
+    fn compute_type(&mut self, ast_node_id: ASTNodeId) -> SemanticResult<()> {
+        use ASTNodeType::*;
+
+        let node = self.ast.lookup(ast_node_id);
+
+        if node.data_type.is_some() {
+            return Ok(());
+        }
+
+        let data_type = match &node.node_data_type {
+            IntLiteral(_) => Some(self.ctx.type_arena.builtin_type_id(Int)),
+            StringLiteral(_) => Some(self.ctx.type_arena.builtin_type_id(String)),
+            InterpolatedString(string) => Some(self.compute_interpolated_string_type(string.parts.clone(), ast_node_id)?),
+
+            List(list) => Some(self.compute_list_type(list.elements.clone())?),
+            Map(map) => Some(self.compute_map_type(map.entries.clone(), ast_node_id)?),
+            Tuple(tuple) => self.compute_tuple_type(tuple.elements.clone())?,
+
+            Variable(var) => match var.type_annotation.clone() {
+                Some(annotation) => Some(self.declare_annotated_variable(var.name, annotation, ast_node_id)?),
+                None => self.compute_variable_type(var.name, node.scope, node.span)?,
+            },
+
+            UnaryOperator(op) => {
+                Some(self.compute_unary_operation_type(op.op_type, op.operand, ast_node_id)?)
+            },
+
+            BinaryOperator(op) => {
+                Some(self.compute_binary_operation_type(op.op_type, op.left, op.right, ast_node_id)?)
+            },
+
+            Conditional(conditional) => {
+                Some(self.compute_conditional_type(conditional.condition, conditional.then_value, conditional.else_value)?)
+            },
+
+            Declaration(declaration) => {
+                Some(self.compute_declaration_type(declaration.kind, declaration.target, declaration.value, ast_node_id)?)
+            },
+
+            Cast(cast) => Some(self.compute_cast_type(cast.operand, cast.target.clone(), ast_node_id)?),
+
+            FunctionDef(func_def) => {
+                Some(self.compute_function_def_type(
+                    func_def.name,
+                    func_def.params.clone(),
+                    func_def.body.clone(),
+                    func_def.body_scope,
+                    ast_node_id
+                )?)
+            },
+
+            FunctionCall(call) => Some(self.compute_function_call_type(call.function, call.args.clone(), ast_node_id)?),
+
+            Return(return_node) => Some(self.compute_return_type(return_node.value, ast_node_id)?),
+
+            Index(index) => Some(self.compute_index_type(index.operand, index.args.clone(), ast_node_id)?),
+
+            Access(access) => {
+                Some(self.compute_access_type(access.receiver, access.member.clone(), ast_node_id)?)
+            },
+
+            If(if_node) => {
+                Some(self.compute_if_type(if_node.condition_blocks.clone(), if_node.else_body.clone())?)
+            },
+
+            While(while_node) => {
+                Some(self.compute_while_type(while_node.condition, while_node.body.clone())?)
+            },
+
+            For(for_node) => {
+                Some(self.compute_for_type(
+                    for_node.item_identifiers.clone(),
+                    for_node.iterator,
+                    for_node.body.clone(),
+                    for_node.body_scope,
+                    ast_node_id
+                )?)
+            },
+
+            StructDef(struct_def) => {
+                Some(self.compute_struct_def_type(struct_def.name, struct_def.methods.clone(), ast_node_id)?)
+            },
+
+            EnumDef(_) | TraitDef(_) | Import(_) => Some(self.ctx.type_arena.builtin_type_id(Unit)),
+
+            Impl(impl_node) => {
+                Some(self.compute_impl_type(impl_node.type_params.clone(), impl_node.methods.clone(), ast_node_id)?)
+            },
+
+            Lambda(lambda) => {
+                Some(self.compute_lambda_type(lambda.params.clone(), lambda.body, lambda.body_scope, ast_node_id)?)
+            },
+        };
+
+        self.ast.lookup_mut(ast_node_id).data_type = data_type;
+
+        Ok(())
+    }
+
+    fn compute_lambda_type(
+        &mut self,
+        params: Vec<LambdaParam>,
+        body: ASTNodeId,
+        body_scope: ScopeId,
+        lambda_node: ASTNodeId,
+    ) -> SemanticResult<DataTypeId> {
+        let node = self.ast.lookup(lambda_node);
+        let (def_span, scope) = (node.span, node.scope);
+        let mut param_types = Vec::new();
+
+        for param in params {
+            let param_type = match &param.type_annotation {
+                Some(annotation) => self.resolve_annotation(annotation, scope)?,
+                None => self.ctx.type_arena.fresh_type_var(),
+            };
+            self.ctx.symbol_table.add_symbol(body_scope, Symbol::new(param.name, param_type, def_span));
+            param_types.push(param_type);
+        }
+
+        self.capture_frames.push(CaptureFrame {
+            body_scope,
+            captures: Vec::new(),
+        });
+        let body_result = self.compute_type(body);
+        let frame = self.capture_frames.pop().expect("Capture frame pushed above");
+        body_result?;
+
+        if let ASTNodeType::Lambda(lambda) = &mut self.ast.lookup_mut(lambda_node).node_data_type {
+            lambda.captures = frame.captures;
+        }
+
+        self.expect_defined(body)?;
+
+        let body_node = self.ast.lookup(body);
+        let return_type = match body_node.data_type {
+            Some(return_type) => return_type,
+            None => return Err(AmbiguousType(self.display_type(None)).at(body_node.span)),
+        };
+
+        Ok(self.ctx.type_arena.function_type_id(param_types, return_type))
+    }
+
+    fn resolve_inferred_types(&mut self) -> SemanticResult<()> {
+        for obligation in std::mem::take(&mut self.hash_obligations) {
+            self.require_hashable(obligation.key_type, obligation.span)?;
+        }
+
+        let mut ambiguous = None;
+
+        for node in self.ast.nodes_mut() {
+            let data_type = match node.data_type {
+                Some(data_type) => self.ctx.type_arena.resolve(data_type),
+                None => continue,
+            };
+
+            if ambiguous.is_none() && self.ctx.type_arena.contains_unsolved(data_type) {
+                ambiguous = Some((data_type, node.span));
+            }
+
+            node.data_type = Some(data_type);
+        }
+
+        match ambiguous {
+            Some((data_type, span)) => Err(AmbiguousType(self.display_type(Some(data_type))).at(span)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn compute_ast_types(ast: AST, ctx: &mut CompilerContext) -> SemanticResult<AST> {
+        let mut synthesizer = TypeSynthesizer::new(ast, ctx);
+
+        let top_level_nodes = synthesizer.ast.top_level_nodes();
+        synthesizer.compute_block_types(&top_level_nodes)?;
+        synthesizer.check_bound_obligations(true)?;
+        synthesizer.resolve_inferred_types()?;
+
+        Ok(synthesizer.ast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module_loader::tests::{compile, compile_error};
+
+    #[test]
+    fn points_type_errors_at_where_the_type_was_inferred() {
+        let error = compile("l = []\nl.push(1)\nx: String = l[0]\n").unwrap_err();
+        let lines: Vec<&str> = error.lines().collect();
+
+        assert_eq!(lines[0], "Error: Cannot assign a value of type Int to a target of type String");
+        assert_eq!(lines[4], "Note: type was inferred from here");
+        assert!(lines[5].ends_with("main.txt:2:7"), "{error}");
+    }
+
+    #[test]
+    fn omits_the_note_when_the_type_was_not_inferred() {
+        let error = compile("x: String = 1\n").unwrap_err();
+
+        assert!(!error.contains("Note:"), "{error}");
+    }
+
+    #[test]
+    fn allows_reassigning_mutable_bindings() {
+        assert_eq!(compile("a = 1\na = 2\na += 3\na++\n"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_assignments_to_let_bindings() {
+        assert_eq!(compile_error("let a = 1\na = 2\n"), "Error: Cannot assign to immutable binding 'a'");
+        assert_eq!(compile_error("let a = 1\na++\n"), "Error: Cannot assign to immutable binding 'a'");
+        assert_eq!(compile_error("let (a, b) = (1, 2)\nb -= 1\n"), "Error: Cannot assign to immutable binding 'b'");
+    }
+
+    #[test]
+    fn rejects_assignments_to_constants() {
+        assert_eq!(compile_error("const A = 1\nA += 2\n"), "Error: Cannot assign to constant 'A'");
+    }
+
+    #[test]
+    fn rejects_assignments_to_functions() {
+        assert_eq!(compile_error("fn f(): Int\n    return 1\n\nf = 2\n"), "Error: Cannot assign to immutable binding 'f'");
     }
 }
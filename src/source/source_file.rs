@@ -2,7 +2,7 @@ use std::fs::read_to_string;
 use std::path::{Display, Path, PathBuf};
 use std::slice::Iter;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SourceFile {
     path: PathBuf,
     lines: Vec<String>,
@@ -20,6 +20,10 @@ impl SourceFile {
         })
     }
     
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     pub fn path_display(&self) -> Display<'_> {
         self.path.display()
     }
@@ -1,6 +1,6 @@
 use crate::source::source_file::SourceFile;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct SourceSpan {
     pub line_index: usize,
     pub start: usize,
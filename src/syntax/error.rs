@@ -16,6 +16,33 @@ pub enum SyntaxError {
     #[error("Error: Invalid Expression")]
     InvalidExpression,
 
+    #[error("Error: Positional argument follows keyword argument")]
+    PositionalArgumentAfterKeyword,
+
+    #[error("Error: Parameter without a default value follows a parameter with one")]
+    RequiredParameterAfterDefault,
+
+    #[error("Error: Operator cannot be overloaded with {0} parameter(s)")]
+    InvalidOperatorOverload(usize),
+
+    #[error("Error: Invalid assignment target, expected a variable, field or index expression")]
+    InvalidAssignmentTarget,
+
+    #[error("Error: Only a name or a tuple of names can be declared")]
+    InvalidDeclarationTarget,
+
+    #[error("Error: A constant must be declared with a single name")]
+    InvalidConstTarget,
+
+    #[error("Error: Imports are only allowed at the top level of a module")]
+    ImportNotAtTopLevel,
+
+    #[error("Error: Only top level functions, types, traits and declarations can be public")]
+    InvalidPubItem,
+
+    #[error("Error: Trait methods cannot have a body")]
+    TraitMethodWithBody,
+
     #[error("Error: Line indented too far in")]
     IndentTooLarge,
 }
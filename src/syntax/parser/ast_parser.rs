@@ -1,37 +1,57 @@
 use crate::ast::arena_ast::{ASTNodeId, AST};
-use crate::ast::ast_node::SpannableASTNode;
+use crate::ast::ast_node::{ASTNodeType, SpannableASTNode};
+use crate::ast::declaration_node::{DeclarationKind, DeclarationNode};
+use crate::ast::enum_def_node::EnumDefNode;
 use crate::ast::for_node::ForNode;
-use crate::ast::function_def_node::FunctionDefNode;
+use crate::ast::function_def_node::{FunctionDefNode, Parameter};
 use crate::ast::if_node::{ConditionBlock, IfNode};
+use crate::ast::impl_node::ImplNode;
+use crate::ast::import_node::{ImportNode, ImportedName};
+use crate::ast::return_node::ReturnNode;
+use crate::ast::struct_def_node::StructDefNode;
+use crate::ast::trait_def_node::TraitDefNode;
 use crate::ast::while_node::WhileNode;
 use crate::error::spanned_error::SpannableError;
 use crate::lexer::token::TokenType::*;
 use crate::lexer::token::TokenType;
 use crate::lexer::tokenizer::TokenizedLines;
-use crate::syntax::error::SyntaxError::IndentTooLarge;
+use crate::syntax::error::SyntaxError::{
+    ExpectedToken, ImportNotAtTopLevel, IndentTooLarge, InvalidConstTarget, InvalidDeclarationTarget, InvalidPubItem,
+    TraitMethodWithBody,
+};
 use crate::syntax::error::SyntaxResult;
 use crate::syntax::parser::expression::ExpressionParser;
-use crate::syntax::parser::function_signature::{parse_function_name, parse_parameters, parse_return_type};
+use crate::syntax::parser::function_signature::{
+    parse_function_name, parse_operator_name, parse_parameters, parse_return_type, parse_type_params, resolve_overloaded_operator,
+};
 use crate::syntax::parser::source_statements::SourceStatements;
 use crate::syntax::parser::statement::Statement;
+use crate::syntax::parser::token_stream::TokenStream;
+use crate::syntax::parser::type_definition::{expect_end_of_statement, parse_field, parse_variant};
+use crate::types::type_annotation::TypeAnnotation;
+use crate::types::type_param::TypeParam;
+use crate::source::source_span::SourceSpan;
+use string_interner::DefaultSymbol;
 use std::iter::Peekable;
 use std::vec::IntoIter;
 use crate::compiler_context::CompilerContext;
-use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::scope::{Scope, ScopeId};
 
 pub struct ASTParser<'a> {
     ast: AST,
     statements_iter: Peekable<IntoIter<Statement>>,
     curr_scope: ScopeId,
+    module_scope: ScopeId,
     ctx: &'a mut CompilerContext,
 }
 
 impl<'a> ASTParser<'a> {
-    pub fn new(statements: SourceStatements, ctx: &'a mut CompilerContext) -> Self {
+    pub fn new(statements: SourceStatements, module_scope: ScopeId, ctx: &'a mut CompilerContext) -> Self {
         Self {
             statements_iter: statements.into_iter(),
             ast: AST::new(),
-            curr_scope: ctx.symbol_table.global_scope(),
+            curr_scope: module_scope,
+            module_scope,
             ctx
         }
     }
@@ -46,8 +66,8 @@ impl<'a> ASTParser<'a> {
         let indent_size = statement.indent_size;
         let mut children = Vec::new();
 
-        while let Some(child) =self.statements_iter.peek() {
-            if child.indent_size < indent_size {
+        while let Some(child) = self.statements_iter.peek() {
+            if child.indent_size <= indent_size {
                 break;
             }
 
@@ -63,29 +83,183 @@ impl<'a> ASTParser<'a> {
         Ok(children)
     }
 
-    fn parse_function(&mut self, func_def_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+    fn parse_children_in_scope(&mut self, statement: &Statement, scope: ScopeId) -> SyntaxResult<Vec<ASTNodeId>> {
+        let parent_scope = self.curr_scope;
+
+        self.curr_scope = scope;
+        let children = self.parse_children(statement);
+        self.curr_scope = parent_scope;
+
+        children
+    }
+
+    fn parse_function(&mut self, func_def_statement: &Statement, receiver: Option<&Parameter>) -> SyntaxResult<ASTNodeId> {
         const TOKENS_BEFORE_NAME: usize = 2;
 
         let mut token_stream = func_def_statement.suffix_stream(TOKENS_BEFORE_NAME);
 
-        let name = parse_function_name(&mut token_stream)?;
-        let params = parse_parameters(&mut token_stream)?;
-        let body = self.parse_children(&func_def_statement)?;
+        let operator_token = parse_operator_name(&mut token_stream);
+        let name = match operator_token {
+            Some(operator_token) => operator_token.symbol,
+            None => parse_function_name(&mut token_stream)?,
+        };
+        let type_params = parse_type_params(&mut token_stream)?;
+        let params = parse_parameters(&mut token_stream, &mut self.ast, &mut self.ctx.symbol_table, self.curr_scope, receiver)?;
+        let operator = operator_token
+            .map(|operator_token| resolve_overloaded_operator(operator_token, params.len()))
+            .transpose()?;
+        let body_scope = self.ctx.symbol_table.add_scope(Scope::with_parent(self.curr_scope));
+        let body = self.parse_children_in_scope(func_def_statement, body_scope)?;
         let return_type = parse_return_type(&mut token_stream)?;
 
         let func_def_node = FunctionDefNode::new(
-            name, params, body, return_type
+            name, type_params, params, body, return_type, body_scope, operator
         ).at(func_def_statement.full_span(), self.curr_scope);
         
         Ok(self.ast.add_node(func_def_node))
     }
 
+    fn next_member(&mut self, type_def_statement: &Statement) -> SyntaxResult<Option<Statement>> {
+        let indent_size = type_def_statement.indent_size;
+
+        match self.statements_iter.peek() {
+            Some(member) if member.indent_size > indent_size => {
+                if indent_size + 1 < member.indent_size {
+                    return Err(IndentTooLarge.at(member.indent_token().span));
+                }
+
+                Ok(self.statements_iter.next())
+            },
+            _ => Ok(None),
+        }
+    }
+
+    fn receiver(&mut self, name: DefaultSymbol, type_params: &[TypeParam], name_span: SourceSpan) -> Parameter {
+        let self_type = TypeAnnotation::with_params(
+            name,
+            type_params.iter().map(|type_param| TypeAnnotation::new(type_param.name, type_param.span)).collect(),
+            name_span,
+        );
+
+        Parameter::new(self.ctx.get_symbol("self"), self_type, None)
+    }
+
+    fn parse_methods(&mut self, block_statement: &Statement, receiver: &Parameter, bodies_allowed: bool) -> SyntaxResult<Vec<ASTNodeId>> {
+        let mut methods = Vec::new();
+
+        while let Some(member) = self.next_member(block_statement)? {
+            if !member.token_after_indent_matches(Fn) {
+                return Err(ExpectedToken(Fn).at(member.token_after_indent().span));
+            }
+
+            if !bodies_allowed && let Some(body) = self.statements_iter.peek() && body.indent_size > member.indent_size {
+                return Err(TraitMethodWithBody.at(body.full_span()));
+            }
+
+            methods.push(self.parse_function(&member, Some(receiver))?);
+        }
+
+        Ok(methods)
+    }
+
+    fn parse_struct(&mut self, struct_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_NAME: usize = 2;
+
+        let mut token_stream = struct_statement.suffix_stream(TOKENS_BEFORE_NAME);
+
+        let name = token_stream.expect_next_identifier()?;
+        let name_span = token_stream.prev_span();
+        let type_params = parse_type_params(&mut token_stream)?;
+        expect_end_of_statement(&mut token_stream)?;
+
+        let receiver = self.receiver(name, &type_params, name_span);
+
+        let mut fields = Vec::new();
+        let mut methods = Vec::new();
+
+        while let Some(member) = self.next_member(struct_statement)? {
+            if member.token_after_indent_matches(Fn) {
+                methods.push(self.parse_function(&member, Some(&receiver))?);
+            } else {
+                fields.push(parse_field(&mut member.suffix_stream(Statement::INDEX_AFTER_INDENT))?);
+            }
+        }
+
+        let struct_node = StructDefNode::new(name, type_params, fields, methods)
+            .at(struct_statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(struct_node))
+    }
+
+    fn parse_enum(&mut self, enum_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_NAME: usize = 2;
+
+        let mut token_stream = enum_statement.suffix_stream(TOKENS_BEFORE_NAME);
+
+        let name = token_stream.expect_next_identifier()?;
+        let type_params = parse_type_params(&mut token_stream)?;
+        expect_end_of_statement(&mut token_stream)?;
+
+        let mut variants = Vec::new();
+
+        while let Some(member) = self.next_member(enum_statement)? {
+            variants.push(parse_variant(&mut member.suffix_stream(Statement::INDEX_AFTER_INDENT))?);
+        }
+
+        let enum_node = EnumDefNode::new(name, type_params, variants)
+            .at(enum_statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(enum_node))
+    }
+
+    fn parse_trait(&mut self, trait_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_NAME: usize = 2;
+
+        let mut token_stream = trait_statement.suffix_stream(TOKENS_BEFORE_NAME);
+
+        let name = token_stream.expect_next_identifier()?;
+        let name_span = token_stream.prev_span();
+        expect_end_of_statement(&mut token_stream)?;
+
+        // Trait methods refer to the implementing type as `Self`
+        let self_type_name = self.ctx.get_symbol("Self");
+        let receiver = self.receiver(self_type_name, &[], name_span);
+        let methods = self.parse_methods(trait_statement, &receiver, false)?;
+
+        let trait_node = TraitDefNode::new(name, methods)
+            .at(trait_statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(trait_node))
+    }
+
+    fn parse_impl(&mut self, impl_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_TRAIT_NAME: usize = 2;
+
+        let mut token_stream = impl_statement.suffix_stream(TOKENS_BEFORE_TRAIT_NAME);
+
+        let trait_name = token_stream.expect_next_identifier()?;
+        token_stream.expect_next_token(For)?;
+        let type_name = token_stream.expect_next_identifier()?;
+        let name_span = token_stream.prev_span();
+        let type_params = parse_type_params(&mut token_stream)?;
+        expect_end_of_statement(&mut token_stream)?;
+
+        let receiver = self.receiver(type_name, &type_params, name_span);
+        let methods = self.parse_methods(impl_statement, &receiver, true)?;
+
+        let impl_node = ImplNode::new(trait_name, type_name, type_params, methods)
+            .at(impl_statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(impl_node))
+    }
+
     fn parse_if_statement(&mut self, if_statement: &Statement) -> SyntaxResult<ASTNodeId> {
         const TOKENS_BEFORE_COND: usize = 2;
 
         let if_cond = ExpressionParser::parse(
             &mut if_statement.suffix_stream(TOKENS_BEFORE_COND),
             &mut self.ast,
+            &mut self.ctx.symbol_table,
             self.curr_scope,
         )?;
         let if_body = self.parse_children(&if_statement)?;
@@ -100,6 +274,7 @@ impl<'a> ASTParser<'a> {
             let elif_cond = ExpressionParser::parse(
                 &mut elif_statement.suffix_stream(TOKENS_BEFORE_COND),
                 &mut self.ast,
+                &mut self.ctx.symbol_table,
                 self.curr_scope,
             )?;
             let elif_body = self.parse_children(&elif_statement)?;
@@ -129,6 +304,7 @@ impl<'a> ASTParser<'a> {
         let while_cond = ExpressionParser::parse(
             &mut while_statement.suffix_stream(TOKENS_BEFORE_COND),
             &mut self.ast,
+            &mut self.ctx.symbol_table,
             self.curr_scope
         )?;
         let while_body = self.parse_children(&while_statement)?;
@@ -144,50 +320,227 @@ impl<'a> ASTParser<'a> {
 
         let mut token_stream = for_statement.suffix_stream(TOKENS_BEFORE_ITEM_IDENT);
 
-        let item_identifier = token_stream.expect_next_identifier()?;
+        let parenthesized = token_stream.peek_matches(OpenParen);
+
+        if parenthesized {
+            token_stream.next();
+        }
+
+        let mut item_identifiers = vec![token_stream.expect_next_identifier()?];
+
+        while token_stream.peek_matches(Comma) {
+            token_stream.next();
+            item_identifiers.push(token_stream.expect_next_identifier()?);
+        }
+
+        if parenthesized {
+            token_stream.expect_next_token(CloseParen)?;
+        }
+
         token_stream.expect_next_token(In)?;
         let iterator = ExpressionParser::parse(
             &mut token_stream,
             &mut self.ast,
+            &mut self.ctx.symbol_table,
             self.curr_scope
         )?;
-        let for_body = self.parse_children(&for_statement)?;
+        let body_scope = self.ctx.symbol_table.add_scope(Scope::with_parent(self.curr_scope));
+        let for_body = self.parse_children_in_scope(for_statement, body_scope)?;
 
-        let node = ForNode::new(item_identifier, iterator, for_body)
+        let node = ForNode::new(item_identifiers, iterator, for_body, body_scope)
             .at(for_statement.full_span(), self.curr_scope);
 
         Ok(self.ast.add_node(node))
     }
 
+    fn parse_return(&mut self, return_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_VALUE: usize = 2;
+
+        let value = if return_statement.len() > TOKENS_BEFORE_VALUE {
+            Some(ExpressionParser::parse_expression_list(
+                &mut return_statement.suffix_stream(TOKENS_BEFORE_VALUE),
+                &mut self.ast,
+                &mut self.ctx.symbol_table,
+                self.curr_scope
+            )?)
+        } else {
+            None
+        };
+
+        let return_node = ReturnNode::new(value)
+            .at(return_statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(return_node))
+    }
+
+    fn check_declaration_target(&self, target: ASTNodeId, kind: DeclarationKind) -> SyntaxResult<()> {
+        let target_node = self.ast.lookup(target);
+
+        match &target_node.node_data_type {
+            ASTNodeType::Variable(_) => Ok(()),
+
+            ASTNodeType::Tuple(_) if kind == DeclarationKind::Const => Err(InvalidConstTarget.at(target_node.span)),
+
+            ASTNodeType::Tuple(tuple) => tuple.elements
+                .iter()
+                .try_for_each(|&element| self.check_declaration_target(element, kind)),
+
+            _ => Err(InvalidDeclarationTarget.at(target_node.span)),
+        }
+    }
+
+    fn parse_declaration(&mut self, statement: &Statement, kind: DeclarationKind) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_TARGET: usize = 2;
+
+        let (target, value) = ExpressionParser::parse_declaration(
+            &mut statement.suffix_stream(TOKENS_BEFORE_TARGET),
+            &mut self.ast,
+            &mut self.ctx.symbol_table,
+            self.curr_scope
+        )?;
+
+        self.check_declaration_target(target, kind)?;
+
+        let declaration_node = DeclarationNode::new(kind, target, value)
+            .at(statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(declaration_node))
+    }
+
+    fn parse_module_path(token_stream: &mut TokenStream) -> SyntaxResult<Vec<DefaultSymbol>> {
+        let mut path = vec![token_stream.expect_next_identifier()?];
+
+        while token_stream.peek_matches(Dot) {
+            token_stream.next();
+            path.push(token_stream.expect_next_identifier()?);
+        }
+
+        Ok(path)
+    }
+
+    fn parse_import(&mut self, import_statement: &Statement) -> SyntaxResult<ASTNodeId> {
+        const TOKENS_BEFORE_PATH: usize = 2;
+
+        if self.curr_scope != self.module_scope {
+            return Err(ImportNotAtTopLevel.at(import_statement.full_span()));
+        }
+
+        let mut token_stream = import_statement.suffix_stream(TOKENS_BEFORE_PATH);
+        let path = Self::parse_module_path(&mut token_stream)?;
+
+        let names = if import_statement.token_after_indent_matches(From) {
+            token_stream.expect_next_token(Import)?;
+
+            let mut names = Vec::new();
+
+            loop {
+                let name = token_stream.expect_next_identifier()?;
+                names.push(ImportedName::new(name, token_stream.prev_span()));
+
+                if !token_stream.peek_matches(Comma) {
+                    break;
+                }
+                token_stream.next();
+            }
+
+            Some(names)
+        } else {
+            None
+        };
+
+        expect_end_of_statement(&mut token_stream)?;
+
+        let import_node = ImportNode::new(path, names)
+            .at(import_statement.full_span(), self.curr_scope);
+
+        Ok(self.ast.add_node(import_node))
+    }
+
+    fn declared_names(&self, target: ASTNodeId, names: &mut Vec<DefaultSymbol>) {
+        match &self.ast.lookup(target).node_data_type {
+            ASTNodeType::Variable(var) => names.push(var.name),
+            ASTNodeType::Tuple(tuple) => tuple.elements
+                .iter()
+                .for_each(|&element| self.declared_names(element, names)),
+            _ => {},
+        }
+    }
+
+    fn export_item(&mut self, item: ASTNodeId, pub_span: SourceSpan) -> SyntaxResult<()> {
+        let item_node = self.ast.lookup(item);
+        let mut names = Vec::new();
+
+        match &item_node.node_data_type {
+            // Operators are usable wherever their operand types are, so they export no name
+            ASTNodeType::FunctionDef(func_def) if func_def.operator.is_some() => {},
+            ASTNodeType::FunctionDef(func_def) => names.push(func_def.name),
+            ASTNodeType::StructDef(struct_def) => names.push(struct_def.name),
+            ASTNodeType::EnumDef(enum_def) => names.push(enum_def.name),
+            ASTNodeType::TraitDef(trait_def) => names.push(trait_def.name),
+            ASTNodeType::Declaration(declaration) => self.declared_names(declaration.target, &mut names),
+            _ => return Err(InvalidPubItem.at(pub_span)),
+        }
+
+        for name in names {
+            self.ast.add_export(name);
+        }
+
+        Ok(())
+    }
+
     fn parse_next_ast_node(&mut self) -> SyntaxResult<Option<ASTNodeId>> {
         
-        if let Some(statement) = &self.statements_iter.next() {
+        if let Some(mut statement) = self.statements_iter.next() {
+
+            let pub_token = match statement.token_after_indent_type() {
+                Pub if statement.len() > Statement::INDEX_AFTER_INDENT + 1 => Some(statement.remove_token_after_indent()),
+                _ => None,
+            };
+
+            let statement = &statement;
+
+            if let Some(pub_token) = &pub_token && self.curr_scope != self.module_scope {
+                return Err(InvalidPubItem.at(pub_token.span));
+            }
 
             let node_id = match statement.token_after_indent_type() {
-                Fn => self.parse_function(statement)?,
+                Fn => self.parse_function(statement, None)?,
+                Struct => self.parse_struct(statement)?,
+                Enum => self.parse_enum(statement)?,
+                Trait => self.parse_trait(statement)?,
+                Impl => self.parse_impl(statement)?,
                 If => self.parse_if_statement(statement)?,
                 While => self.parse_while_loop(statement)?,
                 For => self.parse_for_loop(statement)?,
-                _ => ExpressionParser::parse(
+                Return => self.parse_return(statement)?,
+                Let => self.parse_declaration(statement, DeclarationKind::Let)?,
+                Const => self.parse_declaration(statement, DeclarationKind::Const)?,
+                Import | From => self.parse_import(statement)?,
+                _ => ExpressionParser::parse_statement(
                     &mut statement.suffix_stream(Statement::INDEX_AFTER_INDENT),
                     &mut self.ast,
+                    &mut self.ctx.symbol_table,
                     self.curr_scope
                 )?,
             };
 
+            if let Some(pub_token) = pub_token {
+                self.export_item(node_id, pub_token.span)?;
+            }
+
             Ok(Some(node_id))
         } else {
             Ok(None)
         }
     }
 
-    pub fn generate_ast(source_lines: TokenizedLines, ctx: &'a mut CompilerContext) -> SyntaxResult<AST> {
+    pub fn generate_ast(source_lines: TokenizedLines, module_scope: ScopeId, ctx: &'a mut CompilerContext) -> SyntaxResult<AST> {
 
         let statements: SourceStatements = source_lines.into();
-        let mut parser = Self::new(statements, ctx);
+        let mut parser = Self::new(statements, module_scope, ctx);
 
         while let Some(node_id) = parser.parse_next_ast_node()? {
-            // parser.ast.add_top_level_node(node_id);
+            parser.ast.add_top_level_node(node_id);
         }
 
         Ok(parser.ast)
@@ -1,29 +1,45 @@
 use crate::ast::access_node::{AccessNode, Member};
+use crate::ast::argument::Argument;
 use crate::ast::arena_ast::{ASTNodeId, AST};
 use crate::ast::ast_node::{ASTNode, ASTNodeType, SpannableASTNode};
 use crate::ast::ast_node::ASTNodeType::Variable;
 use crate::ast::binary_operator_node::{BinaryOperatorNode};
+use crate::ast::cast_node::CastNode;
+use crate::ast::conditional_node::ConditionalNode;
 use crate::ast::function_call_node::FunctionCallNode;
 use crate::ast::index_node::IndexNode;
+use crate::ast::lambda_node::LambdaNode;
+use crate::ast::list_node::ListNode;
+use crate::ast::map_node::{MapEntry, MapNode};
+use crate::ast::interpolated_string_node::InterpolatedStringNode;
+use crate::ast::string_literal_node::StringLiteralNode;
+use crate::ast::tuple_node::TupleNode;
 use crate::ast::unary_operator_node::{UnaryOperatorNode};
 use crate::ast::variable_node::VariableNode;
-use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::scope::{Scope, ScopeId};
+use crate::compiler_context::symbol_table::SymbolTable;
 use crate::error::spanned_error::SpannableError;
+use crate::lexer::error::LexerError::IntLiteralOutOfRange;
 use crate::lexer::token::TokenType::*;
-use crate::lexer::token::{Token, TokenType};
+use crate::lexer::token::{LiteralValue, StringPart, Token, TokenType};
 use crate::operators::binary_operators::BinaryOperator;
 use crate::operators::unary_operators::UnaryOperator;
-use crate::syntax::error::SyntaxError::{InvalidExpression, UnmatchedGroupOpening};
+use crate::source::source_span::SourceSpan;
+use crate::syntax::error::SyntaxError::{
+    ExpectedToken, InvalidAssignmentTarget, InvalidExpression, PositionalArgumentAfterKeyword, UnmatchedGroupOpening,
+};
 use crate::syntax::error::SyntaxResult;
 use crate::syntax::parser::expression::OperatorPrecedence::Prefix;
+use crate::syntax::parser::function_signature::parse_lambda_parameters;
 use crate::syntax::parser::token_stream::TokenStream;
 use crate::syntax::parser::type_annotation::parse_type_annotation;
 
 #[repr(u8)]
 #[derive(Copy, Clone)]
 enum OperatorPrecedence {
-    Comma = 0,
-    Assign,
+    Assign = 0,
+    Conditional,
+    Range,
     LogicalOr,
     LogicalAnd,
     BitOr,
@@ -34,6 +50,7 @@ enum OperatorPrecedence {
     BitShift,
     Add,
     Mul,
+    Cast,
     Prefix,
     Postfix,
 }
@@ -56,8 +73,6 @@ fn operators_with_lhs_precedence(op: &Token) -> Option<(u8, u8)> {
     use OperatorPrecedence::*;
 
     Some(match op.token_type {
-        TokenType::Comma => Comma.left_assoc(),
-
         Equals
         | PlusEquals
         | MinusEquals
@@ -71,6 +86,12 @@ fn operators_with_lhs_precedence(op: &Token) -> Option<(u8, u8)> {
         | PipeEquals
         => Assign.right_assoc(),
 
+        If => Conditional.right_assoc(),
+
+        DoubleDot
+        | DoubleDotEquals
+        => Range.left_assoc(),
+
         DoublePipe => LogicalOr.left_assoc(),
 
         DoubleAmpersand => LogicalAnd.left_assoc(),
@@ -89,6 +110,7 @@ fn operators_with_lhs_precedence(op: &Token) -> Option<(u8, u8)> {
         | LessEquals
         | Greater
         | GreaterEquals
+        | In
         => Relational.left_assoc(),
 
         DoubleLeftArrow
@@ -104,6 +126,8 @@ fn operators_with_lhs_precedence(op: &Token) -> Option<(u8, u8)> {
         | Percent
         => Mul.left_assoc(),
 
+        As => Cast.left_assoc(),
+
         PlusPlus
         | MinusMinus
         | OpenParen
@@ -116,7 +140,7 @@ fn operators_with_lhs_precedence(op: &Token) -> Option<(u8, u8)> {
 }
 
 
-fn binary_operator_type(op: &Token) -> Option<BinaryOperator> {
+pub(super) fn binary_operator_type(op: &Token) -> Option<BinaryOperator> {
     use BinaryOperator::*;
 
     Some(match op.token_type {
@@ -151,17 +175,19 @@ fn binary_operator_type(op: &Token) -> Option<BinaryOperator> {
         LessEquals => LessOrEqual,
         Greater => GreaterThan,
         GreaterEquals => GreaterOrEqual,
+        TokenType::In => BinaryOperator::In,
 
         DoubleAmpersand => LogicalAnd,
         DoublePipe => LogicalOr,
 
-        Comma => CommaOperator,
+        DoubleDot => Range,
+        DoubleDotEquals => RangeInclusive,
 
         _ => return None,
     })
 }
 
-fn prefix_unary_operator_type(op: &Token) -> Option<UnaryOperator> {
+pub(super) fn prefix_unary_operator_type(op: &Token) -> Option<UnaryOperator> {
     use UnaryOperator::*;
 
    Some(match op.token_type {
@@ -184,8 +210,19 @@ fn postfix_unary_operator_type(op: &Token) -> Option<UnaryOperator> {
     })
 }
 
+fn is_assignment(token: &Token) -> bool {
+    matches!(operators_with_lhs_precedence(token), Some(precedence) if precedence == OperatorPrecedence::Assign.right_assoc())
+}
+
 fn is_terminal(token: &Token) -> bool {
-    matches!(token.token_type, CloseParen | CloseBracket | Colon)
+    matches!(token.token_type, CloseParen | CloseBracket | CloseBrace | Colon | Comma | Else)
+}
+
+fn int_literal_magnitude(token: &Token) -> u64 {
+    match token.literal {
+        Some(LiteralValue::Int(magnitude)) => magnitude,
+        _ => unreachable!("Integer literal tokens must carry their value"),
+    }
 }
 
 fn close_token(open_token: &Token) -> TokenType {
@@ -194,40 +231,82 @@ fn close_token(open_token: &Token) -> TokenType {
     match open_token.token_type {
         OpenParen => CloseParen,
         OpenBracket => CloseBracket,
+        OpenBrace => CloseBrace,
         _ => unreachable!("Invalid group opening token"),
     }
 }
 
-pub struct ExpressionParser<'a> {
-    token_stream: &'a mut TokenStream<'a>,
+pub struct ExpressionParser<'a, 't> {
+    token_stream: &'a mut TokenStream<'t>,
     ast: &'a mut AST,
+    symbol_table: &'a mut SymbolTable,
     scope: ScopeId,
+    annotations_allowed: bool,
 }
 
-impl<'a> ExpressionParser<'a> {
-    pub fn new(token_stream: &'a mut TokenStream<'a>, ast: &'a mut AST, scope: ScopeId) -> Self {
+impl<'a, 't> ExpressionParser<'a, 't> {
+    pub fn new(token_stream: &'a mut TokenStream<'t>, ast: &'a mut AST, symbol_table: &'a mut SymbolTable, scope: ScopeId) -> Self {
         Self {
             token_stream,
             ast,
-            scope
+            symbol_table,
+            scope,
+            annotations_allowed: true,
         }
     }
 
     fn parse_token(&mut self, token: &Token) -> SyntaxResult<ASTNodeId> {
         use ASTNodeType::*;
 
-        let token_symbol = token.symbol;
         let token_span = token.span;
 
         let node = match token.token_type {
-            TokenType::IntLiteral    => ASTNode::new(IntLiteral(token_symbol), token_span, self.scope),
-            TokenType::StringLiteral => ASTNode::new(StringLiteral(token_symbol), token_span, self.scope),
+            TokenType::IntLiteral    => {
+                let value = i64::try_from(int_literal_magnitude(token))
+                    .map_err(|_| IntLiteralOutOfRange(int_literal_magnitude(token).to_string()).at(token_span))?;
+
+                ASTNode::new(IntLiteral(value), token_span, self.scope)
+            },
+            TokenType::StringLiteral => return self.parse_string_literal(token),
             _ => return Err(InvalidExpression.at(token_span))
         };
 
         Ok(self.ast.add_node(node))
     }
 
+    fn parse_string_literal(&mut self, token: &Token) -> SyntaxResult<ASTNodeId> {
+        let string_parts = match &token.literal {
+            Some(LiteralValue::String(string_parts)) => string_parts,
+            _ => unreachable!("String literal tokens must carry their parts"),
+        };
+
+        if let [StringPart::Text { value, .. }] = string_parts.as_slice() {
+            return Ok(self.ast.add_node(StringLiteralNode::new(token.symbol, *value).at(token.span, self.scope)));
+        }
+
+        let parts = string_parts
+            .iter()
+            .map(|part| match part {
+                StringPart::Text { raw, value, span } => {
+                    Ok(self.ast.add_node(StringLiteralNode::new(*raw, *value).at(*span, self.scope)))
+                },
+                StringPart::Expression(tokens) => self.parse_embedded_expression(tokens, token.span),
+            })
+            .collect::<SyntaxResult<Vec<_>>>()?;
+
+        Ok(self.ast.add_node(InterpolatedStringNode::new(parts).at(token.span, self.scope)))
+    }
+
+    fn parse_embedded_expression(&mut self, tokens: &[Token], string_span: SourceSpan) -> SyntaxResult<ASTNodeId> {
+        let mut token_stream = TokenStream::new(tokens, string_span);
+        let expression = ExpressionParser::parse(&mut token_stream, self.ast, self.symbol_table, self.scope)?;
+
+        match token_stream.peek() {
+            Some(token) => Err(InvalidExpression.at(token.span)),
+            None => Ok(expression),
+        }
+    }
+
     fn assert_group_closed(&mut self, open_token: &Token) -> SyntaxResult<()> {
         if self.token_stream.peek_matches(close_token(open_token)) {
             self.token_stream.next();
@@ -237,48 +316,213 @@ impl<'a> ExpressionParser<'a> {
         }
     }
 
-    fn parse_required_grouped_expression(&mut self, open_token: &Token) -> SyntaxResult<ASTNodeId> {
+    fn parse_tuple_elements(&mut self, first: ASTNodeId, element_precedence: u8) -> SyntaxResult<Vec<ASTNodeId>> {
+        let mut elements = vec![first];
+
+        while self.token_stream.peek_matches(Comma) {
+            self.token_stream.next();
+
+            if self.token_stream.peek_matches(CloseParen) {
+                break;
+            }
+
+            elements.push(self.parse_expression_rec(element_precedence)?);
+        }
+
+        Ok(elements)
+    }
+
+    fn parse_group_or_tuple(&mut self, open_token: &Token) -> SyntaxResult<ASTNodeId> {
         if self.token_stream.empty() {
             return Err(UnmatchedGroupOpening(open_token.token_type).at(open_token.span));
         }
 
-        let group = self.parse_expression_rec(0)?;
+        let first = self.parse_expression_rec(OperatorPrecedence::Assign.as_u8())?;
+
+        if !self.token_stream.peek_matches(Comma) {
+            self.assert_group_closed(open_token)?;
+            return Ok(first);
+        }
 
+        let elements = self.parse_tuple_elements(first, OperatorPrecedence::Assign.as_u8())?;
         self.assert_group_closed(open_token)?;
-        Ok(group)
+
+        let tuple_node = TupleNode::new(elements).at(open_token.span, self.scope);
+        Ok(self.ast.add_node(tuple_node))
     }
 
-    fn parse_optional_grouped_expression(&mut self, open_token: &Token) -> SyntaxResult<Option<ASTNodeId>> {
-        let group = match self.token_stream.peek() {
-            Some(&token) => {
-                if *token == CloseParen {
-                    None
-                } else {
-                    Some(self.parse_expression_rec(0)?)
+    fn parse_unparenthesized_tuple(&mut self, element_precedence: u8) -> SyntaxResult<ASTNodeId> {
+        let first = self.parse_expression_rec(element_precedence)?;
+
+        let comma_span = match self.token_stream.peek() {
+            Some(&token) if *token == Comma => token.span,
+            _ => return Ok(first),
+        };
+
+        let elements = self.parse_tuple_elements(first, element_precedence)?;
+
+        let tuple_node = TupleNode::new(elements).at(comma_span, self.scope);
+        Ok(self.ast.add_node(tuple_node))
+    }
+
+    fn check_assignment_target(&self, target: ASTNodeId, allow_destructure: bool) -> SyntaxResult<()> {
+        let target_node = self.ast.lookup(target);
+
+        match &target_node.node_data_type {
+            ASTNodeType::Variable(_) | ASTNodeType::Index(_) => Ok(()),
+
+            ASTNodeType::Access(access) if matches!(access.member, Member::Field { .. }) => Ok(()),
+
+            ASTNodeType::Tuple(tuple) if allow_destructure => tuple.elements
+                .iter()
+                .try_for_each(|&element| self.check_assignment_target(element, allow_destructure)),
+
+            _ => Err(InvalidAssignmentTarget.at(target_node.span)),
+        }
+    }
+
+    fn parse_statement_expression(&mut self) -> SyntaxResult<ASTNodeId> {
+        let target = self.parse_unparenthesized_tuple(OperatorPrecedence::Range.as_u8())?;
+
+        let assign_token = match self.token_stream.peek() {
+            Some(&token) if is_assignment(token) => token,
+            _ => return Ok(target),
+        };
+
+        self.token_stream.next();
+
+        let op_type = binary_operator_type(assign_token).expect("Assignment must be a binary operator");
+        self.check_assignment_target(target, op_type == BinaryOperator::Assign)?;
+
+        let value = self.parse_unparenthesized_tuple(OperatorPrecedence::Assign.as_u8())?;
+
+        let assign_node = BinaryOperatorNode::new(op_type, target, value).at(assign_token.span, self.scope);
+        Ok(self.ast.add_node(assign_node))
+    }
+
+    fn parse_argument(&mut self) -> SyntaxResult<Argument> {
+        let start_span = match self.token_stream.peek() {
+            Some(&token) => token.span,
+            None => return Err(InvalidExpression.at(self.token_stream.end_span())),
+        };
+
+        let next_token = self.token_stream.peek().copied();
+
+        let keyword = match (next_token, self.token_stream.peek_second()) {
+            (Some(name), Some(colon)) if *name == Identifier && *colon == Colon => {
+                self.token_stream.next();
+                self.token_stream.next();
+                Some(name.symbol)
+            },
+            _ => None,
+        };
+
+        let value = self.parse_expression_without_annotations(OperatorPrecedence::Assign.as_u8())?;
+
+        Ok(Argument::new(keyword, value, self.token_stream.span_since(start_span)))
+    }
+
+    fn parse_arguments(&mut self, open_token: &Token) -> SyntaxResult<Vec<Argument>> {
+        let mut args: Vec<Argument> = Vec::new();
+        let close = close_token(open_token);
+
+        if !self.token_stream.peek_matches(close) && !self.token_stream.empty() {
+            loop {
+                let arg = self.parse_argument()?;
+
+                if arg.keyword.is_none() && args.last().is_some_and(|prev| prev.keyword.is_some()) {
+                    return Err(PositionalArgumentAfterKeyword.at(arg.span));
+                }
+
+                args.push(arg);
+
+                if !self.token_stream.peek_matches(Comma) {
+                    break;
+                }
+                self.token_stream.next();
+
+                if self.token_stream.peek_matches(close) {
+                    break;
                 }
             }
-            None => return Err(UnmatchedGroupOpening(open_token.token_type).at(open_token.span))
-        };
+        }
 
         self.assert_group_closed(open_token)?;
-        Ok(group)
+        Ok(args)
+    }
+
+    fn parse_list_literal(&mut self, open_token: &Token) -> SyntaxResult<ASTNodeId> {
+        let mut elements = Vec::new();
+
+        if !self.token_stream.peek_matches(CloseBracket) && !self.token_stream.empty() {
+            elements.push(self.parse_expression_rec(OperatorPrecedence::Assign.as_u8())?);
+
+            while self.token_stream.peek_matches(Comma) {
+                self.token_stream.next();
+
+                if self.token_stream.peek_matches(CloseBracket) {
+                    break;
+                }
+
+                elements.push(self.parse_expression_rec(OperatorPrecedence::Assign.as_u8())?);
+            }
+        }
+
+        self.assert_group_closed(open_token)?;
+
+        let list_node = ListNode::new(elements).at(open_token.span, self.scope);
+        Ok(self.ast.add_node(list_node))
+    }
+
+    fn parse_expression_without_annotations(&mut self, curr_precedence: u8) -> SyntaxResult<ASTNodeId> {
+        let annotations_allowed = self.annotations_allowed;
+
+        self.annotations_allowed = false;
+        let expression = self.parse_expression_rec(curr_precedence);
+        self.annotations_allowed = annotations_allowed;
+
+        expression
+    }
+
+    fn parse_map_entry(&mut self) -> SyntaxResult<MapEntry> {
+        let key = self.parse_expression_without_annotations(OperatorPrecedence::Assign.as_u8())?;
+        self.token_stream.expect_next_token(Colon)?;
+        let value = self.parse_expression_rec(OperatorPrecedence::Assign.as_u8())?;
+
+        Ok(MapEntry::new(key, value))
+    }
+
+    fn parse_map_literal(&mut self, open_token: &Token) -> SyntaxResult<ASTNodeId> {
+        let mut entries = Vec::new();
+
+        if !self.token_stream.peek_matches(CloseBrace) && !self.token_stream.empty() {
+            entries.push(self.parse_map_entry()?);
+
+            while self.token_stream.peek_matches(Comma) {
+                self.token_stream.next();
+
+                if self.token_stream.peek_matches(CloseBrace) {
+                    break;
+                }
+
+                entries.push(self.parse_map_entry()?);
+            }
+        }
+
+        self.assert_group_closed(open_token)?;
+
+        let map_node = MapNode::new(entries).at(open_token.span, self.scope);
+        Ok(self.ast.add_node(map_node))
     }
 
     fn parse_accessed_member(&mut self) -> SyntaxResult<Member> {
         let member_name = self.token_stream.expect_next_token(Identifier)?;
         let member_name_symbol = member_name.symbol;
 
-        if self.token_stream.peek_matches(OpenParen) {
+        if let Some(&open_token) = self.token_stream.peek() && *open_token == OpenParen {
             self.token_stream.next();
-
-            let member = if self.token_stream.peek_matches(CloseParen) {
-                Ok(Member::method_no_args(member_name_symbol))
-            } else {
-                let args = self.parse_expression_rec(0)?;
-                Ok(Member::method_with_args(member_name_symbol, args))
-            };
-            self.token_stream.next();
-            member
+            let args = self.parse_arguments(open_token)?;
+            Ok(Member::method(member_name_symbol, args))
 
         } else {
             Ok(Member::field(member_name_symbol))
@@ -286,7 +530,7 @@ impl<'a> ExpressionParser<'a> {
     }
 
     fn parse_variable(&mut self, token: &Token) -> SyntaxResult<ASTNodeId> {
-        let type_annotation = if self.token_stream.peek_matches(Colon) {
+        let type_annotation = if self.annotations_allowed && self.token_stream.peek_matches(Colon) {
             self.token_stream.next();
             Some(parse_type_annotation(&mut self.token_stream)?)
         } else {
@@ -297,6 +541,24 @@ impl<'a> ExpressionParser<'a> {
         Ok(self.ast.add_node(var_node))
     }
 
+    fn parse_lambda(&mut self, open_token: &Token) -> SyntaxResult<ASTNodeId> {
+        let params = if *open_token == Pipe {
+            parse_lambda_parameters(self.token_stream)?
+        } else {
+            Vec::new()
+        };
+
+        let body_scope = self.symbol_table.add_scope(Scope::with_parent(self.scope));
+
+        let enclosing_scope = self.scope;
+        self.scope = body_scope;
+        let body = self.parse_expression_rec(OperatorPrecedence::Assign.as_u8());
+        self.scope = enclosing_scope;
+
+        let lambda_node = LambdaNode::new(params, body?, body_scope).at(open_token.span, self.scope);
+        Ok(self.ast.add_node(lambda_node))
+    }
+
     fn nud_hook(&mut self) -> SyntaxResult<ASTNodeId> {
 
         match self.token_stream.next() {
@@ -304,17 +566,33 @@ impl<'a> ExpressionParser<'a> {
 
             Some(token) => {
                 if let Some(unary_op_type) = prefix_unary_operator_type(token) {
-                    let unary_node = UnaryOperatorNode::new(
-                        unary_op_type,
-                        self.parse_expression_rec(Prefix.as_u8())?
-                    ).at(token.span, self.scope);
+                    if unary_op_type == UnaryOperator::Neg && let Some(literal) = self.parse_negated_int_literal(token) {
+                        return Ok(literal);
+                    }
+
+                    let operand = self.parse_expression_rec(Prefix.as_u8())?;
+
+                    if unary_op_type.is_increment() {
+                        self.check_assignment_target(operand, false)?;
+                    }
+
+                    let unary_node = UnaryOperatorNode::new(unary_op_type, operand).at(token.span, self.scope);
                     Ok(self.ast.add_node(unary_node))
 
                 } else if *token == Identifier {
                     self.parse_variable(token)
 
                 } else if *token == OpenParen {
-                    self.parse_required_grouped_expression(token)
+                    self.parse_group_or_tuple(token)
+
+                } else if *token == OpenBracket {
+                    self.parse_list_literal(token)
+
+                } else if *token == OpenBrace {
+                    self.parse_map_literal(token)
+
+                } else if *token == Pipe || *token == DoublePipe {
+                    self.parse_lambda(token)
 
                 } else {
                     self.parse_token(token)
@@ -327,24 +605,39 @@ impl<'a> ExpressionParser<'a> {
         let token_span = token.span;
 
         let node = if let Some(op_type) = binary_operator_type(token) {
+            if op_type.is_assignment() {
+                self.check_assignment_target(left_node, op_type == BinaryOperator::Assign)?;
+            }
+
             let right_node = self.parse_expression_rec(right_precedence)?;
             BinaryOperatorNode::new(op_type, left_node, right_node).at(token_span, self.scope)
 
         } else if let Some(op_type) = postfix_unary_operator_type(token) {
+            self.check_assignment_target(left_node, false)?;
             UnaryOperatorNode::new(op_type, left_node).at(token_span, self.scope)
 
         } else if *token == OpenBracket {
-            let args = self.parse_required_grouped_expression(token)?;
+            let args = self.parse_arguments(token)?;
             IndexNode::new(left_node, args).at(token_span, self.scope)
 
         } else if *token == OpenParen {
-            let args = self.parse_optional_grouped_expression(token)?;
+            let args = self.parse_arguments(token)?;
             FunctionCallNode::new(left_node, args).at(token_span, self.scope)
 
         } else if *token == Dot {
             let member = self.parse_accessed_member()?;
             AccessNode::new(left_node, member).at(token_span, self.scope)
 
+        } else if *token == If {
+            let condition = self.parse_expression_rec(OperatorPrecedence::Range.as_u8())?;
+            self.token_stream.expect_next_token(Else)?;
+            let else_value = self.parse_expression_rec(right_precedence)?;
+            ConditionalNode::new(condition, left_node, else_value).at(token_span, self.scope)
+
+        } else if *token == As {
+            let target = parse_type_annotation(self.token_stream)?;
+            CastNode::new(left_node, target).at(token_span, self.scope)
+
         } else {
             unreachable!("Led hook not implemented");
         };
@@ -352,6 +645,27 @@ impl<'a> ExpressionParser<'a> {
         Ok(self.ast.add_node(node))
     }
 
+    /// Folds a minus into the integer literal right after it, unless a postfix operator binds the
+    /// literal first, so that the most negative Int can be written.
+    fn parse_negated_int_literal(&mut self, minus_token: &Token) -> Option<ASTNodeId> {
+        let literal_token = self.token_stream.peek().copied().filter(|&token| *token == TokenType::IntLiteral)?;
+
+        let binds_literal_first = self.token_stream.peek_second().is_some_and(|token| {
+            !is_terminal(token) && operators_with_lhs_precedence(token).is_none_or(|(left, _)| left >= Prefix.as_u8())
+        });
+
+        if binds_literal_first {
+            return None;
+        }
+
+        let value = 0i64.checked_sub_unsigned(int_literal_magnitude(literal_token))?;
+        self.token_stream.next();
+
+        let span = SourceSpan::new(minus_token.span.line_index, minus_token.span.start, literal_token.span.end);
+
+        Some(self.ast.add_node(ASTNode::new(ASTNodeType::IntLiteral(value), span, self.scope)))
+    }
+
     fn parse_expression_rec(&mut self, curr_precedence: u8) -> SyntaxResult<ASTNodeId> {
 
         if self.token_stream.empty() {
@@ -382,7 +696,61 @@ impl<'a> ExpressionParser<'a> {
         Ok(left_node_id)
     }
 
-    pub fn parse(token_stream: &'a mut TokenStream<'a>, ast_arena: &'a mut AST, scope: ScopeId) -> SyntaxResult<ASTNodeId> {
-        ExpressionParser::new(token_stream, ast_arena, scope).parse_expression_rec(0)
+    pub fn parse(token_stream: &'a mut TokenStream<'t>, ast_arena: &'a mut AST, symbol_table: &'a mut SymbolTable, scope: ScopeId) -> SyntaxResult<ASTNodeId> {
+        ExpressionParser::new(token_stream, ast_arena, symbol_table, scope).parse_expression_rec(0)
+    }
+
+    pub fn parse_partial(token_stream: &'a mut TokenStream<'t>, ast_arena: &'a mut AST, symbol_table: &'a mut SymbolTable, scope: ScopeId) -> SyntaxResult<ASTNodeId> {
+        ExpressionParser::new(token_stream, ast_arena, symbol_table, scope).parse_expression_rec(OperatorPrecedence::Assign.as_u8())
+    }
+
+    pub fn parse_expression_list(token_stream: &'a mut TokenStream<'t>, ast_arena: &'a mut AST, symbol_table: &'a mut SymbolTable, scope: ScopeId) -> SyntaxResult<ASTNodeId> {
+        ExpressionParser::new(token_stream, ast_arena, symbol_table, scope).parse_unparenthesized_tuple(OperatorPrecedence::Assign.as_u8())
+    }
+
+    pub fn parse_declaration(token_stream: &'a mut TokenStream<'t>, ast_arena: &'a mut AST, symbol_table: &'a mut SymbolTable, scope: ScopeId) -> SyntaxResult<(ASTNodeId, ASTNodeId)> {
+        let mut parser = ExpressionParser::new(token_stream, ast_arena, symbol_table, scope);
+
+        if parser.token_stream.empty() {
+            return Err(ExpectedToken(Identifier).at(parser.token_stream.end_span()));
+        }
+
+        let target = parser.parse_unparenthesized_tuple(OperatorPrecedence::Range.as_u8())?;
+        parser.token_stream.expect_next_token(Equals)?;
+        let value = parser.parse_unparenthesized_tuple(OperatorPrecedence::Assign.as_u8())?;
+
+        Ok((target, value))
+    }
+
+    pub fn parse_statement(token_stream: &'a mut TokenStream<'t>, ast_arena: &'a mut AST, symbol_table: &'a mut SymbolTable, scope: ScopeId) -> SyntaxResult<ASTNodeId> {
+        ExpressionParser::new(token_stream, ast_arena, symbol_table, scope).parse_statement_expression()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module_loader::tests::{compile, compile_error};
+
+    const INVALID_TARGET: &str = "Error: Invalid assignment target, expected a variable, field or index expression";
+
+    #[test]
+    fn accepts_variables_fields_and_indices_as_targets() {
+        let source = "struct P\n    x: Int\n\np = P(1)\np.x = 2\nl = [1, 2]\nl[0] += 1\nl[1]++\na, b = 1, 2\na, p.x = b, a\n";
+
+        assert_eq!(compile(source), Ok(()));
+    }
+
+    #[test]
+    fn rejects_other_expressions_as_targets() {
+        assert_eq!(compile_error("1 = 2\n"), INVALID_TARGET);
+        assert_eq!(compile_error("a = 1\na + 1 = 2\n"), INVALID_TARGET);
+        assert_eq!(compile_error("fn f(): Int\n    return 1\n\nf() = 2\n"), INVALID_TARGET);
+        assert_eq!(compile_error("fn f(): Int\n    return 1\n\nf()++\n"), INVALID_TARGET);
+    }
+
+    #[test]
+    fn only_destructures_plain_assignments() {
+        assert_eq!(compile_error("a = 1\nb = 2\na, b += 1, 2\n"), INVALID_TARGET);
+        assert_eq!(compile_error("a = 1\na, 1 = 1, 2\n"), INVALID_TARGET);
     }
 }
@@ -1,26 +1,140 @@
 use string_interner::DefaultSymbol;
+use crate::ast::arena_ast::AST;
 use crate::ast::function_def_node::Parameter;
+use crate::ast::lambda_node::LambdaParam;
+use crate::compiler_context::scope::ScopeId;
+use crate::compiler_context::symbol_table::SymbolTable;
 use crate::error::spanned_error::SpannableError;
-use crate::lexer::token::TokenType::{CloseParen, Colon, Comma, Identifier, OpenParen};
-use crate::syntax::error::SyntaxError::UnexpectedExpression;
+use crate::lexer::token::TokenType::{CloseParen, Colon, Comma, Equals, Greater, Identifier, Less, OpenParen, Pipe, Plus};
+use crate::lexer::token::Token;
+use crate::operators::binary_operators::BinaryOperator;
+use crate::operators::overloaded_operator::OverloadedOperator;
+use crate::operators::unary_operators::UnaryOperator;
+use crate::syntax::error::SyntaxError::{InvalidOperatorOverload, RequiredParameterAfterDefault, UnexpectedExpression};
 use crate::syntax::error::SyntaxResult;
+use crate::syntax::parser::expression::{binary_operator_type, prefix_unary_operator_type, ExpressionParser};
 use crate::syntax::parser::token_stream::TokenStream;
 use crate::syntax::parser::type_annotation::parse_type_annotation;
 use crate::types::type_annotation::TypeAnnotation;
+use crate::types::type_param::TypeParam;
 
 pub fn parse_function_name(token_stream: &mut TokenStream) -> SyntaxResult<DefaultSymbol> {
     Ok(token_stream.expect_next_identifier()?)
 }
 
-fn parse_parameter(token_stream: &mut TokenStream) -> SyntaxResult<Parameter> {
+pub fn parse_operator_name<'a>(token_stream: &mut TokenStream<'a>) -> Option<&'a Token> {
+    let token = *token_stream.peek()?;
+
+    if binary_operator_type(token).is_some() || prefix_unary_operator_type(token).is_some() {
+        token_stream.next()
+    } else {
+        None
+    }
+}
+
+pub fn resolve_overloaded_operator(operator_token: &Token, param_count: usize) -> SyntaxResult<OverloadedOperator> {
+    let operator = match param_count {
+        1 => prefix_unary_operator_type(operator_token)
+            .filter(UnaryOperator::is_overloadable)
+            .map(OverloadedOperator::Unary),
+        2 => binary_operator_type(operator_token)
+            .filter(BinaryOperator::is_overloadable)
+            .map(OverloadedOperator::Binary),
+        _ => None,
+    };
+
+    operator.ok_or_else(|| InvalidOperatorOverload(param_count).at(operator_token.span))
+}
+
+fn parse_type_param(token_stream: &mut TokenStream) -> SyntaxResult<TypeParam> {
+    let name = token_stream.expect_next_identifier()?;
+    let span = token_stream.prev_span();
+    let mut bounds = Vec::new();
+
+    if token_stream.peek_matches(Colon) {
+        token_stream.next();
+        bounds.push(token_stream.expect_next_identifier()?);
+
+        while token_stream.peek_matches(Plus) {
+            token_stream.next();
+            bounds.push(token_stream.expect_next_identifier()?);
+        }
+    }
+
+    Ok(TypeParam::new(name, bounds, token_stream.span_since(span)))
+}
+
+pub fn parse_type_params(token_stream: &mut TokenStream) -> SyntaxResult<Vec<TypeParam>> {
+    let mut type_params = Vec::new();
+
+    if !token_stream.peek_matches(Less) {
+        return Ok(type_params);
+    }
+
+    token_stream.next();
+    type_params.push(parse_type_param(token_stream)?);
+
+    while token_stream.peek_matches(Comma) {
+        token_stream.next();
+        type_params.push(parse_type_param(token_stream)?);
+    }
+
+    token_stream.expect_next_token(Greater)?;
+
+    Ok(type_params)
+}
+
+fn parse_parameter(token_stream: &mut TokenStream, ast: &mut AST, symbol_table: &mut SymbolTable, scope: ScopeId) -> SyntaxResult<Parameter> {
     let param_name = token_stream.expect_next_token(Identifier)?.symbol;
     token_stream.expect_next_token(Colon)?;
     let type_annotation = parse_type_annotation(token_stream)?;
 
-    Ok(Parameter::new(param_name, type_annotation))
+    let default_value = if token_stream.peek_matches(Equals) {
+        token_stream.next();
+        Some(ExpressionParser::parse_partial(token_stream, ast, symbol_table, scope)?)
+    } else {
+        None
+    };
+
+    Ok(Parameter::new(param_name, type_annotation, default_value))
 }
 
-pub fn parse_parameters(token_stream: &mut TokenStream) -> SyntaxResult<Vec<Parameter>> {
+fn push_parameter(params: &mut Vec<Parameter>, token_stream: &mut TokenStream, ast: &mut AST, symbol_table: &mut SymbolTable, scope: ScopeId) -> SyntaxResult<()> {
+    let param_span = token_stream.peek().map(|&token| token.span);
+    let param = parse_parameter(token_stream, ast, symbol_table, scope)?;
+
+    let follows_default = params.last().is_some_and(|prev| prev.default_value.is_some());
+
+    if param.default_value.is_none() && follows_default {
+        let span = param_span.unwrap_or_else(|| token_stream.prev_span());
+        return Err(RequiredParameterAfterDefault.at(span));
+    }
+
+    params.push(param);
+    Ok(())
+}
+
+fn parse_receiver(token_stream: &mut TokenStream, receiver: Option<&Parameter>) -> Option<Parameter> {
+    let receiver = receiver?;
+
+    let is_receiver = token_stream.peek().is_some_and(|&token| *token == Identifier && token.symbol == receiver.name)
+        && !token_stream.peek_second().is_some_and(|token| *token == Colon);
+
+    if is_receiver {
+        token_stream.next();
+        Some(receiver.clone())
+    } else {
+        None
+    }
+}
+
+pub fn parse_parameters(
+    token_stream: &mut TokenStream,
+    ast: &mut AST,
+    symbol_table: &mut SymbolTable,
+    scope: ScopeId,
+    receiver: Option<&Parameter>,
+) -> SyntaxResult<Vec<Parameter>> {
     token_stream.expect_next_token(OpenParen)?;
 
     let mut params = Vec::new();
@@ -30,11 +144,14 @@ pub fn parse_parameters(token_stream: &mut TokenStream) -> SyntaxResult<Vec<Para
         return Ok(params);
     }
 
-    params.push(parse_parameter(token_stream)?);
+    match parse_receiver(token_stream, receiver) {
+        Some(receiver) => params.push(receiver),
+        None => push_parameter(&mut params, token_stream, ast, symbol_table, scope)?,
+    }
 
     while token_stream.peek_matches(Comma) {
         token_stream.next();
-        params.push(parse_parameter(token_stream)?);
+        push_parameter(&mut params, token_stream, ast, symbol_table, scope)?;
     }
 
     token_stream.expect_next_token(CloseParen)?;
@@ -42,6 +159,37 @@ pub fn parse_parameters(token_stream: &mut TokenStream) -> SyntaxResult<Vec<Para
     Ok(params)
 }
 
+pub fn parse_lambda_parameters(token_stream: &mut TokenStream) -> SyntaxResult<Vec<LambdaParam>> {
+    let mut params = Vec::new();
+
+    if token_stream.peek_matches(Pipe) {
+        token_stream.next();
+        return Ok(params);
+    }
+
+    loop {
+        let param_name = token_stream.expect_next_token(Identifier)?.symbol;
+
+        let type_annotation = if token_stream.peek_matches(Colon) {
+            token_stream.next();
+            Some(parse_type_annotation(token_stream)?)
+        } else {
+            None
+        };
+
+        params.push(LambdaParam::new(param_name, type_annotation));
+
+        if !token_stream.peek_matches(Comma) {
+            break;
+        }
+        token_stream.next();
+    }
+
+    token_stream.expect_next_token(Pipe)?;
+
+    Ok(params)
+}
+
 fn parse_return_type_annotation(token_stream: &mut TokenStream) -> SyntaxResult<TypeAnnotation> {
     token_stream.next();
     let type_annotation = parse_type_annotation(token_stream)?;
@@ -47,6 +47,10 @@ impl Statement {
         self.token_after_indent_type() == &token_type
     }
 
+    pub fn remove_token_after_indent(&mut self) -> Token {
+        self.tokens.remove(Self::INDEX_AFTER_INDENT)
+    }
+
     pub fn last_token(&self) -> &Token {
         self.last().expect("Statement must have at least one token")
     }
@@ -57,7 +61,7 @@ impl Statement {
     }
 
     pub fn suffix_stream(&self, start: usize) -> TokenStream<'_> {
-        TokenStream::new(&self[start..])
+        TokenStream::new(&self[start..], self[start - 1].span)
     }
 }
 
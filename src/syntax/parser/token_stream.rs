@@ -10,16 +10,16 @@ use crate::syntax::error::SyntaxError::ExpectedToken;
 
 pub struct TokenStream<'a> {
     iter: Peekable<Iter<'a, Token>>,
-    prev_token: &'a Token,
+    prev_span: SourceSpan,
     curr_token_split: bool,
 
 }
 
 impl<'a> TokenStream<'a> {
-    pub fn new(tokens: &'a [Token]) -> Self {
+    pub fn new(tokens: &'a [Token], start_span: SourceSpan) -> Self {
         Self {
             iter: tokens.iter().peekable(),
-            prev_token: &tokens[0],
+            prev_span: start_span,
             curr_token_split: false
         }
     }
@@ -28,12 +28,26 @@ impl<'a> TokenStream<'a> {
         self.iter.peek()
     }
 
+    pub fn peek_second(&self) -> Option<&'a Token> {
+        self.iter.clone().nth(1)
+    }
+
     pub fn empty(&mut self) -> bool{
         self.peek().is_none()
     }
     
     pub fn prev_span(&self) -> SourceSpan {
-        self.prev_token.span
+        self.prev_span
+    }
+
+    pub fn span_since(&self, start: SourceSpan) -> SourceSpan {
+        let end = self.prev_span();
+
+        if end.line_index == start.line_index && end.end >= start.start {
+            SourceSpan::new(start.line_index, start.start, end.end)
+        } else {
+            start
+        }
     }
 
     pub(crate) fn end_span(&mut self) -> SourceSpan {
@@ -82,7 +96,7 @@ impl<'a> Iterator for TokenStream<'a> {
         let token_opt = self.iter.next();
 
         if let Some(token) = token_opt {
-            self.prev_token = token;
+            self.prev_span = token.span;
         }
 
         self.curr_token_split = false;
@@ -33,14 +33,15 @@ fn parse_inner_types(token_stream: &mut TokenStream) -> SyntaxResult<Vec<TypeAnn
 pub fn parse_type_annotation(token_stream: &mut TokenStream) -> SyntaxResult<TypeAnnotation> {
 
     let type_name = token_stream.expect_next_identifier()?;
+    let name_span = token_stream.prev_span();
 
     if token_stream.peek_matches(Less) {
         token_stream.next();
         let inner_types = parse_inner_types(token_stream)?;
         assert_type_params_closed(token_stream)?;
-        Ok(TypeAnnotation::with_params(type_name, inner_types))
+        Ok(TypeAnnotation::with_params(type_name, inner_types, token_stream.span_since(name_span)))
         
     } else {
-        Ok(TypeAnnotation::new(type_name))
+        Ok(TypeAnnotation::new(type_name, name_span))
     }
 }
@@ -0,0 +1,44 @@
+use crate::ast::enum_def_node::VariantDef;
+use crate::ast::struct_def_node::FieldDef;
+use crate::error::spanned_error::SpannableError;
+use crate::lexer::token::TokenType::{CloseParen, Colon, Comma, OpenParen};
+use crate::syntax::error::SyntaxError::UnexpectedExpression;
+use crate::syntax::error::SyntaxResult;
+use crate::syntax::parser::token_stream::TokenStream;
+use crate::syntax::parser::type_annotation::parse_type_annotation;
+
+pub fn expect_end_of_statement(token_stream: &mut TokenStream) -> SyntaxResult<()> {
+    match token_stream.next() {
+        None => Ok(()),
+        Some(token) => Err(UnexpectedExpression.at(token.span)),
+    }
+}
+
+pub fn parse_field(token_stream: &mut TokenStream) -> SyntaxResult<FieldDef> {
+    let name = token_stream.expect_next_identifier()?;
+    token_stream.expect_next_token(Colon)?;
+    let type_annotation = parse_type_annotation(token_stream)?;
+
+    expect_end_of_statement(token_stream)?;
+    Ok(FieldDef::new(name, type_annotation))
+}
+
+pub fn parse_variant(token_stream: &mut TokenStream) -> SyntaxResult<VariantDef> {
+    let name = token_stream.expect_next_identifier()?;
+    let mut payload = Vec::new();
+
+    if token_stream.peek_matches(OpenParen) {
+        token_stream.next();
+        payload.push(parse_type_annotation(token_stream)?);
+
+        while token_stream.peek_matches(Comma) {
+            token_stream.next();
+            payload.push(parse_type_annotation(token_stream)?);
+        }
+
+        token_stream.expect_next_token(CloseParen)?;
+    }
+
+    expect_end_of_statement(token_stream)?;
+    Ok(VariantDef::new(name, payload))
+}
@@ -1,2 +1,7 @@
 pub mod data_type;
 pub mod type_annotation;
+pub mod function_signature;
+pub mod user_type;
+pub mod type_param;
+pub mod trait_def;
+pub mod const_value;
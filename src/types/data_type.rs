@@ -1,14 +1,88 @@
-use strum::EnumIter;
+use strum::{Display, EnumIter};
 use string_interner::DefaultSymbol;
+use crate::compiler_context::module::ModuleId;
+use crate::compiler_context::CompilerContext;
+use crate::compiler_context::type_arena::{DataTypeId, UserTypeId};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum DataType {
     Builtin(BuiltinType),
-    UserDefined(DefaultSymbol),
-    // TODO: generics
+    UserDefined(UserTypeId, Vec<DataTypeId>),
+    Range(DataTypeId),
+    List(DataTypeId),
+    Map(DataTypeId, DataTypeId),
+    Tuple(Vec<DataTypeId>),
+    Function(Vec<DataTypeId>, DataTypeId),
+    TypeParam(DefaultSymbol),
+    TypeVar(usize),
+    Module(ModuleId),
 }
 
-#[derive(Debug, PartialEq, EnumIter)]
+impl DataType {
+    pub fn is_hashable(&self) -> bool {
+        matches!(self, DataType::Builtin(BuiltinType::Bool | BuiltinType::Int | BuiltinType::String) | DataType::TypeParam(_))
+    }
+
+    pub fn display(&self, ctx: &CompilerContext) -> String {
+        match self {
+            DataType::Builtin(builtin_type) => builtin_type.to_string(),
+            DataType::TypeParam(name) => match ctx.get_str(*name) {
+                Some(s) => s.to_string(),
+                None => format!("<invalid symbol {:?}>", name),
+            },
+            DataType::TypeVar(index) => match ctx.type_arena.type_var_binding(*index) {
+                Some(binding) => ctx.type_arena.get(binding).display(ctx),
+                None => "_".to_string(),
+            },
+            DataType::UserDefined(user_type, type_args) => {
+                let name = ctx.type_arena.user_type(*user_type).name;
+                let name = match ctx.get_str(name) {
+                    Some(s) => s.to_string(),
+                    None => format!("<invalid symbol {:?}>", name),
+                };
+
+                if type_args.is_empty() {
+                    return name;
+                }
+
+                let type_arg_names = type_args.iter()
+                    .map(|&type_arg| ctx.type_arena.get(type_arg).display(ctx))
+                    .collect::<Vec<_>>();
+                format!("{}<{}>", name, type_arg_names.join(", "))
+            },
+            DataType::Range(element_type) => {
+                format!("Range<{}>", ctx.type_arena.get(*element_type).display(ctx))
+            },
+            DataType::List(element_type) => {
+                format!("List<{}>", ctx.type_arena.get(*element_type).display(ctx))
+            },
+            DataType::Map(key_type, value_type) => {
+                format!("Map<{}, {}>",
+                        ctx.type_arena.get(*key_type).display(ctx),
+                        ctx.type_arena.get(*value_type).display(ctx))
+            },
+            DataType::Tuple(element_types) => {
+                let element_names = element_types.iter()
+                    .map(|&element_type| ctx.type_arena.get(element_type).display(ctx))
+                    .collect::<Vec<_>>();
+                format!("Tuple<{}>", element_names.join(", "))
+            },
+            DataType::Module(module) => match ctx.get_str(ctx.modules.module(*module).name) {
+                Some(s) => format!("Module {}", s),
+                None => "Module".to_string(),
+            },
+            DataType::Function(param_types, return_type) => {
+                let type_names = param_types.iter()
+                    .chain(std::iter::once(return_type))
+                    .map(|&data_type| ctx.type_arena.get(data_type).display(ctx))
+                    .collect::<Vec<_>>();
+                format!("Fn<{}>", type_names.join(", "))
+            },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, EnumIter, Display)]
 pub enum BuiltinType {
     Unit = 0,
     Bool,
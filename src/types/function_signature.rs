@@ -0,0 +1,50 @@
+use string_interner::DefaultSymbol;
+use crate::compiler_context::type_arena::DataTypeId;
+use crate::types::type_param::TypeParam;
+
+#[derive(Debug, Clone)]
+pub struct ParamSignature {
+    pub name: Option<DefaultSymbol>,
+    pub data_type: DataTypeId,
+    pub has_default: bool,
+}
+
+impl ParamSignature {
+    pub fn new(name: Option<DefaultSymbol>, data_type: DataTypeId, has_default: bool) -> Self {
+        Self {
+            name,
+            data_type,
+            has_default,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FunctionSignature {
+    pub type_params: Vec<TypeParam>,
+    pub params: Vec<ParamSignature>,
+    pub return_type: DataTypeId,
+}
+
+impl FunctionSignature {
+    pub fn new(type_params: Vec<TypeParam>, params: Vec<ParamSignature>, return_type: DataTypeId) -> Self {
+        Self {
+            type_params,
+            params,
+            return_type,
+        }
+    }
+
+    pub fn positional(param_types: Vec<DataTypeId>, return_type: DataTypeId) -> Self {
+        let params = param_types
+            .into_iter()
+            .map(|data_type| ParamSignature::new(None, data_type, false))
+            .collect();
+
+        Self::new(Vec::new(), params, return_type)
+    }
+
+    pub fn param_types(&self) -> Vec<DataTypeId> {
+        self.params.iter().map(|param| param.data_type).collect()
+    }
+}
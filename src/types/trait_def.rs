@@ -0,0 +1,24 @@
+use string_interner::DefaultSymbol;
+use crate::types::function_signature::FunctionSignature;
+
+#[derive(Debug, Clone)]
+pub struct TraitDef {
+    pub name: DefaultSymbol,
+    pub methods: Vec<(DefaultSymbol, FunctionSignature)>,
+}
+
+impl TraitDef {
+    pub fn new(name: DefaultSymbol) -> Self {
+        Self {
+            name,
+            methods: Vec::new(),
+        }
+    }
+
+    pub fn method(&self, method_name: DefaultSymbol) -> Option<&FunctionSignature> {
+        self.methods
+            .iter()
+            .find(|(name, _)| *name == method_name)
+            .map(|(_, signature)| signature)
+    }
+}
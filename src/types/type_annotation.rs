@@ -1,23 +1,27 @@
 use string_interner::DefaultSymbol;
+use crate::source::source_span::SourceSpan;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TypeAnnotation {
-    type_name: DefaultSymbol,
-    inner_types: Vec<TypeAnnotation>,
+    pub type_name: DefaultSymbol,
+    pub inner_types: Vec<TypeAnnotation>,
+    pub span: SourceSpan,
 }
 
 impl TypeAnnotation {
-    pub fn new(type_name: DefaultSymbol) -> Self {
+    pub fn new(type_name: DefaultSymbol, span: SourceSpan) -> Self {
         Self {
             type_name,
             inner_types: Vec::new(),
+            span,
         }
     }
 
-    pub fn with_params(type_name: DefaultSymbol, inner_types: Vec<TypeAnnotation>) -> Self {
+    pub fn with_params(type_name: DefaultSymbol, inner_types: Vec<TypeAnnotation>, span: SourceSpan) -> Self {
         Self {
             type_name,
             inner_types,
+            span,
         }
     }
 
@@ -0,0 +1,24 @@
+use string_interner::DefaultSymbol;
+use crate::compiler_context::type_arena::TraitId;
+use crate::source::source_span::SourceSpan;
+
+#[derive(Debug, Clone)]
+pub struct TypeParam {
+    pub name: DefaultSymbol,
+    pub bounds: Vec<DefaultSymbol>,
+    pub traits: Vec<TraitId>,
+    pub formattable: bool,
+    pub span: SourceSpan,
+}
+
+impl TypeParam {
+    pub fn new(name: DefaultSymbol, bounds: Vec<DefaultSymbol>, span: SourceSpan) -> Self {
+        Self {
+            name,
+            bounds,
+            traits: Vec::new(),
+            formattable: false,
+            span,
+        }
+    }
+}
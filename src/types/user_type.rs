@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use string_interner::DefaultSymbol;
+use crate::compiler_context::type_arena::{DataTypeId, TraitId};
+use crate::types::function_signature::FunctionSignature;
+use crate::types::type_param::TypeParam;
+
+#[derive(Debug, Clone)]
+pub enum UserTypeKind {
+    Struct {
+        fields: Vec<(DefaultSymbol, DataTypeId)>,
+    },
+    Enum {
+        variants: Vec<(DefaultSymbol, Vec<DataTypeId>)>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct UserTypeDef {
+    pub name: DefaultSymbol,
+    pub type_params: Vec<TypeParam>,
+    pub kind: UserTypeKind,
+    pub methods: HashMap<DefaultSymbol, FunctionSignature>,
+    pub traits: HashMap<TraitId, Vec<TypeParam>>,
+}
+
+impl UserTypeDef {
+    pub fn new(name: DefaultSymbol, type_params: Vec<TypeParam>, kind: UserTypeKind) -> Self {
+        Self {
+            name,
+            type_params,
+            kind,
+            methods: HashMap::new(),
+            traits: HashMap::new(),
+        }
+    }
+
+    pub fn field_type(&self, field_name: DefaultSymbol) -> Option<DataTypeId> {
+        match &self.kind {
+            UserTypeKind::Struct { fields } => fields
+                .iter()
+                .find(|(name, _)| *name == field_name)
+                .map(|&(_, data_type)| data_type),
+            UserTypeKind::Enum { .. } => None,
+        }
+    }
+}